@@ -0,0 +1,182 @@
+from glob import glob
+from time import time
+from pathlib import Path
+
+import conllu
+import pandas as pd
+
+from vtext.tokenize_sentence import (
+    UnicodeSentenceTokenizer,
+    PunctuationTokenizer,
+    PunktSentenceTokenizer,
+)
+
+try:
+    import nltk
+except ImportError:
+    nltk = None
+
+try:
+    import spacy
+except ImportError:
+    spacy = None
+
+base_dir = Path(__file__).parent.parent
+base_dir = base_dir / "ud-treebanks-v2.3/"
+
+
+def load_documents(tb_path):
+    """Load a treebank as a list of documents
+
+    Each document is a list of paragraphs, and each paragraph a list of sentences.
+    """
+    with tb_path.open("rt") as fh:
+        treebank = conllu.parse(fh.read())
+    documents = []
+    for sentence in treebank:
+        if "newdoc id" in sentence.metadata or not documents:
+            documents.append([])
+        if "newpar" in sentence.metadata or not documents[-1]:
+            documents[-1].append([])
+        documents[-1][-1].append(sentence.metadata["text"])
+    return documents
+
+
+def document_text(document):
+    """Text of a document, and the character offsets of the sentence boundaries"""
+    text = ""
+    boundaries = set()
+    for paragraph in document:
+        for idx, sentence in enumerate(paragraph):
+            if text:
+                # boundaries are recorded at the end of the previous sentence,
+                # before the separating whitespace
+                boundaries.add(len(text))
+                text += " " if idx > 0 else "\n\n"
+            text += sentence.strip()
+    return text, boundaries
+
+
+def evaluate_tokenizer(documents, tokenizer):
+    """Precision, recall and F1 score of the detected sentence boundaries"""
+    n_true, n_pred, n_correct = 0, 0, 0
+    for document in documents:
+        text, boundaries = document_text(document)
+        predicted = set()
+        for sentence in tokenizer(text)[:-1]:
+            _, start, end = sentence
+            # boundaries are compared without trailing whitespace
+            predicted.add(start + len(text[start:end].rstrip()))
+        n_true += len(boundaries)
+        n_pred += len(predicted)
+        n_correct += len(boundaries & predicted)
+    precision = n_correct / max(n_pred, 1)
+    recall = n_correct / max(n_true, 1)
+    f1 = 2 * precision * recall / max(precision + recall, 1e-12)
+    return precision, recall, f1
+
+
+def with_offsets(tokenize):
+    """Compute character offsets for a tokenizer returning a list of sentences"""
+
+    def tokenize_with_offsets(text):
+        sentences = []
+        start = 0
+        for sentence in tokenize(text):
+            start = text.index(sentence, start)
+            sentences.append((sentence, start, start + len(sentence)))
+            start += len(sentence)
+        return sentences
+
+    return tokenize_with_offsets
+
+
+tb_list = [
+    ("en", "GUM"),
+    ("en", "EWT"),
+    ("fr", "Sequoia"),
+    ("de", "GSD"),
+]
+
+
+def punkt_tokenizer(lang, train_texts):
+    tokenizer = PunktSentenceTokenizer()
+    tokenizer.fit(train_texts)
+    return tokenizer.tokenize_with_offsets
+
+
+tok_db = [
+    (
+        "unicode-segmentation",
+        lambda lang, train_texts: UnicodeSentenceTokenizer().tokenize_with_offsets,
+    ),
+    (
+        "punctuation",
+        lambda lang, train_texts: PunctuationTokenizer().tokenize_with_offsets,
+    ),
+    (
+        "punctuation-abbreviations",
+        lambda lang, train_texts: PunctuationTokenizer(lang=lang).tokenize_with_offsets,
+    ),
+    ("punkt", punkt_tokenizer),
+]
+
+if nltk is not None:
+
+    def nltk_punkt_tokenizer(lang, train_texts):
+        from nltk.tokenize.punkt import PunktSentenceTokenizer as NLTKPunkt
+
+        tokenizer = NLTKPunkt("\n\n".join(train_texts))
+        return lambda text: [
+            (text[start:end], start, end)
+            for start, end in tokenizer.span_tokenize(text)
+        ]
+
+    tok_db.append(("nltk-punkt", nltk_punkt_tokenizer))
+
+if spacy is not None:
+
+    def spacy_tokenizer(lang, train_texts):
+        nlp = spacy.blank(lang)
+        nlp.add_pipe(nlp.create_pipe("sentencizer"))
+        return with_offsets(lambda text: [sent.text for sent in nlp(text).sents])
+
+    tok_db.append(("spacy-sentencizer", spacy_tokenizer))
+
+out = []
+for lang, tb_name in tb_list:
+    tb_paths = {}
+    for split in ["train", "test"]:
+        tb_pattern = base_dir / "*" / f"{lang}_{tb_name.lower()}-ud-{split}.conllu"
+        tb_path = list(glob(str(tb_pattern)))
+        if len(tb_path) != 1:
+            raise ValueError(tb_path)
+        tb_paths[split] = Path(tb_path[0])
+
+    t0 = time()
+    train_texts = [document_text(doc)[0] for doc in load_documents(tb_paths["train"])]
+    test_documents = load_documents(tb_paths["test"])
+    print(f"Loaded {tb_name} in {time() - t0:.2f}s")
+    for name, get_tokenizer in tok_db:
+        t0 = time()
+        tokenizer = get_tokenizer(lang, train_texts)
+        precision, recall, f1 = evaluate_tokenizer(test_documents, tokenizer)
+        print(f"{tb_name} done with {name} in {time() - t0:.2f}s")
+        out.append(
+            {
+                "treebank": tb_name,
+                "lang": lang,
+                "tokenizer": name,
+                "precision": precision,
+                "recall": recall,
+                "f1": f1,
+            }
+        )
+
+out = (
+    pd.DataFrame(out)
+    .set_index(["lang", "treebank", "tokenizer"])
+    .f1.unstack(-1)
+    .round(3)
+)
+print(out)
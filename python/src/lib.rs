@@ -179,6 +179,9 @@ fn _lib(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<tokenize::UnicodeWordTokenizer>()?;
     m.add_class::<tokenize_sentence::UnicodeSentenceTokenizer>()?;
     m.add_class::<tokenize_sentence::PunctuationTokenizer>()?;
+    m.add_class::<tokenize_sentence::PunktSentenceTokenizer>()?;
+    m.add_class::<tokenize_sentence::ParagraphTokenizer>()?;
+    m.add_class::<tokenize_sentence::DocumentTokenizer>()?;
     m.add_class::<tokenize::RegexpTokenizer>()?;
     m.add_class::<tokenize::VTextTokenizer>()?;
     m.add_class::<tokenize::CharacterTokenizer>()?;
@@ -5,9 +5,8 @@
 // modified, or distributed except according to those terms.
 
 use crate::utils::{deserialize_params, serialize_params};
-use pyo3::exceptions;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use vtext::stem::*;
 
 /// __init__(self, lang='english')
 ///
@@ -18,33 +17,7 @@ use pyo3::types::PyDict;
 /// for Rust.
 #[pyclass(module = "vtext.stem")]
 pub struct SnowballStemmer {
-    pub lang: String,
-    inner: rust_stemmers::Stemmer,
-}
-
-fn get_algorithm(lang: &str) -> PyResult<rust_stemmers::Algorithm> {
-    match lang {
-        "arabic" => Ok(rust_stemmers::Algorithm::Arabic),
-        "danish" => Ok(rust_stemmers::Algorithm::Danish),
-        "dutch" => Ok(rust_stemmers::Algorithm::Dutch),
-        "english" => Ok(rust_stemmers::Algorithm::English),
-        "french" => Ok(rust_stemmers::Algorithm::French),
-        "german" => Ok(rust_stemmers::Algorithm::German),
-        "greek" => Ok(rust_stemmers::Algorithm::Greek),
-        "hungarian" => Ok(rust_stemmers::Algorithm::Hungarian),
-        "italian" => Ok(rust_stemmers::Algorithm::Italian),
-        "portuguese" => Ok(rust_stemmers::Algorithm::Portuguese),
-        "romanian" => Ok(rust_stemmers::Algorithm::Romanian),
-        "russian" => Ok(rust_stemmers::Algorithm::Russian),
-        "spanish" => Ok(rust_stemmers::Algorithm::Spanish),
-        "swedish" => Ok(rust_stemmers::Algorithm::Swedish),
-        "tamil" => Ok(rust_stemmers::Algorithm::Tamil),
-        "turkish" => Ok(rust_stemmers::Algorithm::Turkish),
-        _ => Err(exceptions::ValueError::py_err(format!(
-            "lang={} is unsupported!",
-            lang
-        ))),
-    }
+    inner: vtext::stem::SnowballStemmer,
 }
 
 #[pymethods]
@@ -52,13 +25,11 @@ impl SnowballStemmer {
     #[new]
     #[args(lang = "\"english\"")]
     fn new(lang: &str) -> PyResult<Self> {
-        let algorithm = get_algorithm(lang)?;
-        let stemmer = rust_stemmers::Stemmer::create(algorithm);
+        let stemmer = vtext::stem::SnowballStemmerParams::default()
+            .lang(lang)
+            .build()?;
 
-        Ok(SnowballStemmer {
-            lang: lang.to_string(),
-            inner: stemmer,
-        })
+        Ok(SnowballStemmer { inner: stemmer })
     }
 
     /// stem(self, word)
@@ -79,20 +50,25 @@ impl SnowballStemmer {
         Ok(res)
     }
 
-    fn get_params<'p>(&self, py: Python<'p>) -> PyResult<&'p PyDict> {
-        let params = PyDict::new(py);
-        params.set_item("lang", self.lang.clone())?;
-        Ok(params)
+    /// get_params(self, x)
+    ///
+    /// Get parameters for this estimator.
+    ///
+    /// Returns
+    /// -------
+    /// params : mapping of string to any
+    ///          Parameter names mapped to their values.
+    fn get_params(&self) -> PyResult<SnowballStemmerParams> {
+        Ok(self.inner.params.clone())
     }
 
     pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
-        serialize_params(&self.lang, py)
+        serialize_params(&self.inner.params, py)
     }
 
     pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
-        self.lang = deserialize_params(py, state)?;
-        let algorithm = get_algorithm(&self.lang)?;
-        self.inner = rust_stemmers::Stemmer::create(algorithm);
+        let mut params: SnowballStemmerParams = deserialize_params(py, state)?;
+        self.inner = params.build()?;
         Ok(())
     }
 }
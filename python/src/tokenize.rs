@@ -5,7 +5,7 @@
 // modified, or distributed except according to those terms.
 
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyDict, PyList};
 
 use crate::utils::{deserialize_params, serialize_params};
 use vtext::tokenize::*;
@@ -34,7 +34,7 @@ impl BaseTokenizer {
 /// - `Unicode® Standard Annex #29 <http://www.unicode.org/reports/tr29/>`_
 #[pyclass(extends=BaseTokenizer, module="vtext.tokenize")]
 pub struct UnicodeWordTokenizer {
-    inner: vtext::tokenize::UnicodeWordTokenizer,
+    pub(crate) inner: vtext::tokenize::UnicodeWordTokenizer,
 }
 
 #[pymethods]
@@ -71,6 +71,30 @@ impl UnicodeWordTokenizer {
         Ok(list)
     }
 
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
     /// get_params(self, x)
     ///
     /// Get parameters for this estimator.
@@ -112,7 +136,7 @@ impl UnicodeWordTokenizer {
 /// - `Unicode® Standard Annex #29 <http://www.unicode.org/reports/tr29/>`_
 #[pyclass(extends=BaseTokenizer, module="vtext.tokenize")]
 pub struct VTextTokenizer {
-    inner: vtext::tokenize::VTextTokenizer,
+    pub(crate) inner: vtext::tokenize::VTextTokenizer,
 }
 
 #[pymethods]
@@ -146,6 +170,30 @@ impl VTextTokenizer {
         Ok(list)
     }
 
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
     /// get_params(self, x)
     ///
     /// Get parameters for this estimator.
@@ -174,7 +222,7 @@ impl VTextTokenizer {
 /// Tokenize a document using regular expressions
 #[pyclass(extends=BaseTokenizer, module="vtext.tokenize")]
 pub struct RegexpTokenizer {
-    inner: vtext::tokenize::RegexpTokenizer,
+    pub(crate) inner: vtext::tokenize::RegexpTokenizer,
 }
 
 #[pymethods]
@@ -208,6 +256,30 @@ impl RegexpTokenizer {
         Ok(list)
     }
 
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
     /// get_params(self, x)
     ///
     /// Get parameters for this estimator.
@@ -231,14 +303,17 @@ impl RegexpTokenizer {
     }
 }
 
-/// __init__(self, window_size=4)
+/// __init__(self, window_size=4, ngram_range=None)
 ///
 /// Character tokenizer
 ///
 /// Parameters
 /// ----------
-/// window_size : str, default=4
+/// window_size : int, default=4
 ///   number of consecutive characters included in a token
+/// ngram_range : tuple (min_n, max_n), default=None
+///   the lower and upper boundary of the range of character n-grams
+///   lengths. If provided, `window_size` is ignored.
 ///
 /// Example
 /// -------
@@ -246,20 +321,29 @@ impl RegexpTokenizer {
 /// >>> tokenizer = CharacterTokenizer(window_size=4)
 /// >>> tokenizer.tokenize('fox can\'t')
 /// ['fox ', 'ox c', 'x ca', ' can', 'can\'', 'an\'t']
+/// >>> tokenizer = CharacterTokenizer(ngram_range=(2, 3))
+/// >>> tokenizer.tokenize('fox')
+/// ['fo', 'fox', 'ox']
 ///
 #[pyclass(extends=BaseTokenizer, module="vtext.tokenize")]
 pub struct CharacterTokenizer {
-    inner: vtext::tokenize::CharacterTokenizer,
+    pub(crate) inner: vtext::tokenize::CharacterTokenizer,
 }
 
 #[pymethods]
 impl CharacterTokenizer {
     #[new]
-    #[args(window_size = 4)]
-    fn new(window_size: usize) -> PyResult<(Self, BaseTokenizer)> {
-        let inner = vtext::tokenize::CharacterTokenizerParams::default()
-            .window_size(window_size)
-            .build()?;
+    #[args(window_size = 4, ngram_range = "None")]
+    fn new(
+        window_size: usize,
+        ngram_range: Option<(usize, usize)>,
+    ) -> PyResult<(Self, BaseTokenizer)> {
+        let mut params =
+            vtext::tokenize::CharacterTokenizerParams::default().window_size(window_size);
+        if let Some((min_n, max_n)) = ngram_range {
+            params = params.ngram_range(min_n, max_n);
+        }
+        let inner = params.build()?;
 
         Ok((CharacterTokenizer { inner }, BaseTokenizer::new()))
     }
@@ -283,6 +367,30 @@ impl CharacterTokenizer {
         Ok(list)
     }
 
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
     /// get_params(self, x)
     ///
     /// Get parameters for this estimator.
@@ -291,8 +399,15 @@ impl CharacterTokenizer {
     /// -------
     /// params : mapping of string to any
     ///          Parameter names mapped to their values.
-    fn get_params(&self) -> PyResult<CharacterTokenizerParams> {
-        Ok(self.inner.params.clone())
+    fn get_params(&self, py: Python) -> PyResult<PyObject> {
+        let params = self.inner.params.clone().into_py(py);
+        // ngram_range is only included when set, fixed size tokenizers are
+        // described by window_size
+        let dict: &PyDict = params.cast_as(py)?;
+        if dict.get_item("ngram_range").map_or(false, |x| x.is_none()) {
+            dict.del_item("ngram_range")?;
+        }
+        Ok(params)
     }
 
     pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
@@ -4,9 +4,10 @@
 // <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
 // modified, or distributed except according to those terms.
 
-use crate::tokenize::BaseTokenizer;
+use crate::tokenize::{self, BaseTokenizer};
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use vtext::persistence::Format;
 use vtext::tokenize::Tokenizer;
 use vtext::tokenize_sentence::*;
 
@@ -62,6 +63,30 @@ impl UnicodeSentenceTokenizer {
         Ok(list)
     }
 
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
     /// get_params(self, x)
     ///
     /// Get parameters for this estimator.
@@ -85,17 +110,29 @@ impl UnicodeSentenceTokenizer {
     }
 }
 
-/// __init__(self, punctuation=[".", "?", "!"])
+/// __init__(self, punctuation=[".", "?", "!"], punctuation_pattern=None, lang=None, abbreviations=[])
 ///
 /// Punctuation sentence tokenizer
 ///
 /// This simple tokenizer uses punctuation (default ".", "?", "!") to determine sentence boundaries.
-/// Trailing whitespace is also captured in the preceding sentence.
+/// Closing quotes and brackets directly following the punctuation, as well as trailing whitespace,
+/// are also captured in the preceding sentence.
+///
+/// A period doesn't end a sentence when it is followed by a letter or a digit (e.g. "3.14"),
+/// when it is part of an ellipsis followed by a lowercase word, when it follows an initial,
+/// or when it follows an abbreviation.
 ///
 /// Parameters
 /// ----------
 /// punctuation : List[str]
-///   Punctuation tokens used to determine boundaries. Only the first unicode "character" is used.
+///   Punctuation tokens used to determine boundaries. Multi-character tokens such as "?!"
+///   or "..." are matched as a whole, the longest matching token first.
+/// punctuation_pattern : str, optional
+///   Regular expression whose matches also determine boundaries.
+/// lang : str, optional
+///   Use the built-in abbreviation list for this language: "en", "fr", "de", "it" or "es".
+/// abbreviations : List[str]
+///   Custom abbreviations without the final period, used in addition to the built-in list.
 ///
 ///
 #[pyclass(extends=BaseTokenizer, module="vtext.tokenize_sentence")]
@@ -106,11 +143,28 @@ pub struct PunctuationTokenizer {
 #[pymethods]
 impl PunctuationTokenizer {
     #[new]
-    #[args(punctuation = "vecString![\".\", \"!\", \"?\"]")]
-    fn new(punctuation: Vec<String>) -> PyResult<(Self, BaseTokenizer)> {
-        let tokenizer = vtext::tokenize_sentence::PunctuationTokenizerParams::default()
-            .punctuation(punctuation)
-            .build()?;
+    #[args(
+        punctuation = "vecString![\".\", \"!\", \"?\"]",
+        punctuation_pattern = "None",
+        lang = "None",
+        abbreviations = "Vec::new()"
+    )]
+    fn new(
+        punctuation: Vec<String>,
+        punctuation_pattern: Option<&str>,
+        lang: Option<&str>,
+        abbreviations: Vec<String>,
+    ) -> PyResult<(Self, BaseTokenizer)> {
+        let mut params = vtext::tokenize_sentence::PunctuationTokenizerParams::default();
+        params.punctuation(punctuation);
+        params.abbreviations(abbreviations);
+        if let Some(pattern) = punctuation_pattern {
+            params.punctuation_pattern(pattern);
+        }
+        if let Some(lang) = lang {
+            params.lang(lang);
+        }
+        let tokenizer = params.build()?;
 
         Ok((
             PunctuationTokenizer { inner: tokenizer },
@@ -137,6 +191,30 @@ impl PunctuationTokenizer {
         Ok(list)
     }
 
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
     /// get_params(self, x)
     ///
     /// Get parameters for this estimator.
@@ -159,3 +237,497 @@ impl PunctuationTokenizer {
         Ok(())
     }
 }
+
+/// __init__(self, abbreviations=[], abbreviation_threshold=0.3, collocation_threshold=7.88, sentence_starter_threshold=30.0)
+///
+/// Punkt sentence tokenizer
+///
+/// Unsupervised sentence tokenizer, that learns abbreviations, collocations and
+/// sentence starters from raw text with the ``fit`` method. Trailing whitespace is
+/// also captured in the preceding sentence.
+///
+/// Parameters
+/// ----------
+/// abbreviations : List[str]
+///   Known abbreviations without the final period, in addition to the learned ones.
+/// abbreviation_threshold : float
+///   Minimum score for a type to be learned as abbreviation.
+/// collocation_threshold : float
+///   Minimum log-likelihood for a pair of types to be learned as collocation.
+/// sentence_starter_threshold : float
+///   Minimum log-likelihood for a type to be learned as sentence starter.
+///
+/// References
+/// ----------
+/// - Kiss, Tibor and Strunk, Jan (2006): Unsupervised Multilingual Sentence Boundary
+///   Detection. Computational Linguistics 32: 485-525.
+#[pyclass(extends=BaseTokenizer, module="vtext.tokenize_sentence")]
+pub struct PunktSentenceTokenizer {
+    inner: vtext::tokenize_sentence::PunktSentenceTokenizer,
+}
+
+#[pymethods]
+impl PunktSentenceTokenizer {
+    #[new]
+    #[args(
+        abbreviations = "Vec::new()",
+        abbreviation_threshold = "0.3",
+        collocation_threshold = "7.88",
+        sentence_starter_threshold = "30.0"
+    )]
+    fn new(
+        abbreviations: Vec<String>,
+        abbreviation_threshold: f64,
+        collocation_threshold: f64,
+        sentence_starter_threshold: f64,
+    ) -> PyResult<(Self, BaseTokenizer)> {
+        let tokenizer = vtext::tokenize_sentence::PunktSentenceTokenizerParams::default()
+            .abbreviations(abbreviations)
+            .abbreviation_threshold(abbreviation_threshold)
+            .collocation_threshold(collocation_threshold)
+            .sentence_starter_threshold(sentence_starter_threshold)
+            .build()?;
+
+        Ok((
+            PunktSentenceTokenizer { inner: tokenizer },
+            BaseTokenizer::new(),
+        ))
+    }
+
+    /// fit(self, x)
+    ///
+    /// Learn abbreviations, collocations and sentence starters from documents
+    ///
+    /// Previously learned parameters are replaced.
+    ///
+    /// Parameters
+    /// ----------
+    /// x : List[str]
+    ///   documents of running text
+    fn fit(&mut self, x: Vec<String>) -> PyResult<()> {
+        self.inner.fit(&x);
+        Ok(())
+    }
+
+    /// tokenize(self, x)
+    ///
+    /// Tokenize a string of sentences
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///   the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[str]
+    ///    computed tokens
+    fn tokenize<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<&str> = self.inner.tokenize(x).collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
+    /// get_abbreviations(self)
+    ///
+    /// Known and learned abbreviations, lowercase without the final period
+    ///
+    /// Returns
+    /// -------
+    /// abbreviations : List[str]
+    fn get_abbreviations(&self) -> PyResult<Vec<String>> {
+        let mut abbreviations: Vec<String> = self.inner.abbreviations.iter().cloned().collect();
+        abbreviations.sort();
+        Ok(abbreviations)
+    }
+
+    /// get_params(self, x)
+    ///
+    /// Get parameters for this estimator.
+    ///
+    /// Returns
+    /// -------
+    /// params : mapping of string to any
+    ///          Parameter names mapped to their values.
+    fn get_params(&self) -> PyResult<PunktSentenceTokenizerParams> {
+        Ok(self.inner.params.clone())
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let mut state: Vec<u8> = Vec::new();
+        vtext::persistence::save(&self.inner, &mut state, Format::Binary)?;
+        Ok(PyBytes::new(py, &state).to_object(py))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let state = state.extract::<&PyBytes>(py)?;
+        self.inner = vtext::persistence::load(state.as_bytes(), Format::Binary)?;
+        Ok(())
+    }
+}
+
+/// __init__(self, split_lines=False)
+///
+/// Paragraph tokenizer
+///
+/// Splits a document into paragraphs separated by blank lines, by the Unicode paragraph
+/// separator or by a form feed. Line breaks can be "\n", "\r\n", "\r" or Unicode line
+/// separators, so that hard-wrapped paragraphs are kept together. Trailing whitespace is
+/// captured in the preceding paragraph.
+///
+/// Parameters
+/// ----------
+/// split_lines : bool, default=False
+///   Split on each line break instead of blank lines.
+#[pyclass(extends=BaseTokenizer, module="vtext.tokenize_sentence")]
+pub struct ParagraphTokenizer {
+    inner: vtext::tokenize_sentence::ParagraphTokenizer,
+}
+
+#[pymethods]
+impl ParagraphTokenizer {
+    #[new]
+    #[args(split_lines = "false")]
+    fn new(split_lines: bool) -> PyResult<(Self, BaseTokenizer)> {
+        let tokenizer = vtext::tokenize_sentence::ParagraphTokenizerParams::default()
+            .split_lines(split_lines)
+            .build()?;
+
+        Ok((
+            ParagraphTokenizer { inner: tokenizer },
+            BaseTokenizer::new(),
+        ))
+    }
+
+    /// tokenize(self, x)
+    ///
+    /// Split a string into paragraphs
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///   the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[str]
+    ///    computed tokens
+    fn tokenize<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<&str> = self.inner.tokenize(x).collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string, and return the position of each token
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// tokens : List[Tuple[str, int, int]]
+    ///    computed tokens, with their start and end character offsets,
+    ///    such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<(&str, usize, usize)> = self
+            .inner
+            .tokenize_with_char_offsets(x)
+            .map(|token| (token.text, token.start, token.end))
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
+    /// get_params(self, x)
+    ///
+    /// Get parameters for this estimator.
+    ///
+    /// Returns
+    /// -------
+    /// params : mapping of string to any
+    ///          Parameter names mapped to their values.
+    fn get_params(&self) -> PyResult<ParagraphTokenizerParams> {
+        Ok(self.inner.params.clone())
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        serialize_params(&self.inner.params, py)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let mut params: ParagraphTokenizerParams = deserialize_params(py, state)?;
+        self.inner = params.build()?;
+        Ok(())
+    }
+}
+
+/// Sentence tokenizers of the Python `DocumentTokenizer`
+#[derive(Debug, Clone)]
+enum DocumentSentenceTokenizer {
+    Unicode(vtext::tokenize_sentence::UnicodeSentenceTokenizer),
+    Punctuation(vtext::tokenize_sentence::PunctuationTokenizer),
+    Punkt(vtext::tokenize_sentence::PunktSentenceTokenizer),
+    Paragraph(vtext::tokenize_sentence::ParagraphTokenizer),
+}
+
+impl Default for DocumentSentenceTokenizer {
+    fn default() -> DocumentSentenceTokenizer {
+        DocumentSentenceTokenizer::Punctuation(
+            vtext::tokenize_sentence::PunctuationTokenizer::default(),
+        )
+    }
+}
+
+impl Tokenizer for DocumentSentenceTokenizer {
+    fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match self {
+            DocumentSentenceTokenizer::Unicode(tokenizer) => tokenizer.tokenize(text),
+            DocumentSentenceTokenizer::Punctuation(tokenizer) => tokenizer.tokenize(text),
+            DocumentSentenceTokenizer::Punkt(tokenizer) => tokenizer.tokenize(text),
+            DocumentSentenceTokenizer::Paragraph(tokenizer) => tokenizer.tokenize(text),
+        }
+    }
+}
+
+impl DocumentSentenceTokenizer {
+    /// Extract the Rust tokenizer of a Python sentence tokenizer
+    fn extract(x: &PyAny) -> PyResult<DocumentSentenceTokenizer> {
+        if let Ok(tokenizer) = x.extract::<PyRef<UnicodeSentenceTokenizer>>() {
+            Ok(DocumentSentenceTokenizer::Unicode(tokenizer.inner.clone()))
+        } else if let Ok(tokenizer) = x.extract::<PyRef<PunctuationTokenizer>>() {
+            Ok(DocumentSentenceTokenizer::Punctuation(
+                tokenizer.inner.clone(),
+            ))
+        } else if let Ok(tokenizer) = x.extract::<PyRef<PunktSentenceTokenizer>>() {
+            Ok(DocumentSentenceTokenizer::Punkt(tokenizer.inner.clone()))
+        } else if let Ok(tokenizer) = x.extract::<PyRef<ParagraphTokenizer>>() {
+            Ok(DocumentSentenceTokenizer::Paragraph(
+                tokenizer.inner.clone(),
+            ))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::TypeError, _>(
+                "sentence_tokenizer must be a sentence tokenizer from vtext.tokenize_sentence",
+            ))
+        }
+    }
+}
+
+/// Word tokenizers of the Python `DocumentTokenizer`
+#[derive(Debug, Clone)]
+enum DocumentWordTokenizer {
+    UnicodeWord(vtext::tokenize::UnicodeWordTokenizer),
+    Regexp(vtext::tokenize::RegexpTokenizer),
+    VText(vtext::tokenize::VTextTokenizer),
+    Character(vtext::tokenize::CharacterTokenizer),
+}
+
+impl Default for DocumentWordTokenizer {
+    fn default() -> DocumentWordTokenizer {
+        DocumentWordTokenizer::UnicodeWord(vtext::tokenize::UnicodeWordTokenizer::default())
+    }
+}
+
+impl Tokenizer for DocumentWordTokenizer {
+    fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match self {
+            DocumentWordTokenizer::UnicodeWord(tokenizer) => tokenizer.tokenize(text),
+            DocumentWordTokenizer::Regexp(tokenizer) => tokenizer.tokenize(text),
+            DocumentWordTokenizer::VText(tokenizer) => tokenizer.tokenize(text),
+            DocumentWordTokenizer::Character(tokenizer) => tokenizer.tokenize(text),
+        }
+    }
+}
+
+impl DocumentWordTokenizer {
+    /// Extract the Rust tokenizer of a Python word tokenizer
+    fn extract(x: &PyAny) -> PyResult<DocumentWordTokenizer> {
+        if let Ok(tokenizer) = x.extract::<PyRef<tokenize::UnicodeWordTokenizer>>() {
+            Ok(DocumentWordTokenizer::UnicodeWord(tokenizer.inner.clone()))
+        } else if let Ok(tokenizer) = x.extract::<PyRef<tokenize::RegexpTokenizer>>() {
+            Ok(DocumentWordTokenizer::Regexp(tokenizer.inner.clone()))
+        } else if let Ok(tokenizer) = x.extract::<PyRef<tokenize::VTextTokenizer>>() {
+            Ok(DocumentWordTokenizer::VText(tokenizer.inner.clone()))
+        } else if let Ok(tokenizer) = x.extract::<PyRef<tokenize::CharacterTokenizer>>() {
+            Ok(DocumentWordTokenizer::Character(tokenizer.inner.clone()))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::TypeError, _>(
+                "word_tokenizer must be a word tokenizer from vtext.tokenize",
+            ))
+        }
+    }
+}
+
+/// __init__(self, sentence_tokenizer=None, word_tokenizer=None)
+///
+/// Tokenize documents into sentences of words
+///
+/// Splits a document into sentences with a sentence tokenizer, then each
+/// sentence into words with a word tokenizer, keeping the offsets of the
+/// words relative to the document.
+///
+/// Parameters
+/// ----------
+/// sentence_tokenizer : tokenizer, default=PunctuationTokenizer()
+///   sentence tokenizer from :mod:`vtext.tokenize_sentence`
+/// word_tokenizer : tokenizer, default=UnicodeWordTokenizer()
+///   word tokenizer from :mod:`vtext.tokenize`
+///
+/// Example
+/// -------
+/// >>> from vtext.tokenize_sentence import DocumentTokenizer
+/// >>> tokenizer = DocumentTokenizer()
+/// >>> tokenizer.tokenize("Hi there. How are you?")
+/// [['Hi', 'there', '.'], ['How', 'are', 'you', '?']]
+#[pyclass(extends=BaseTokenizer, module="vtext.tokenize_sentence")]
+pub struct DocumentTokenizer {
+    inner: vtext::tokenize_sentence::DocumentTokenizer<
+        DocumentSentenceTokenizer,
+        DocumentWordTokenizer,
+    >,
+    sentence_tokenizer: Option<PyObject>,
+    word_tokenizer: Option<PyObject>,
+}
+
+impl DocumentTokenizer {
+    fn from_tokenizers(
+        py: Python,
+        sentence_tokenizer: Option<&PyAny>,
+        word_tokenizer: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let mut params = vtext::tokenize_sentence::DocumentTokenizerParams::default();
+        if let Some(tokenizer) = sentence_tokenizer {
+            params.sentence_tokenizer(DocumentSentenceTokenizer::extract(tokenizer)?);
+        }
+        if let Some(tokenizer) = word_tokenizer {
+            params.word_tokenizer(DocumentWordTokenizer::extract(tokenizer)?);
+        }
+        Ok(DocumentTokenizer {
+            inner: params.build()?,
+            sentence_tokenizer: sentence_tokenizer.map(|x| x.to_object(py)),
+            word_tokenizer: word_tokenizer.map(|x| x.to_object(py)),
+        })
+    }
+}
+
+#[pymethods]
+impl DocumentTokenizer {
+    #[new]
+    #[args(sentence_tokenizer = "None", word_tokenizer = "None")]
+    fn new(
+        py: Python,
+        sentence_tokenizer: Option<&PyAny>,
+        word_tokenizer: Option<&PyAny>,
+    ) -> PyResult<(Self, BaseTokenizer)> {
+        Ok((
+            DocumentTokenizer::from_tokenizers(py, sentence_tokenizer, word_tokenizer)?,
+            BaseTokenizer::new(),
+        ))
+    }
+
+    /// tokenize(self, x)
+    ///
+    /// Tokenize a string into sentences of words
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///   the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// sentences : List[List[str]]
+    ///    words of each sentence
+    fn tokenize<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<Vec<&str>> = self
+            .inner
+            .tokenize_sentences(x)
+            .map(|sentence| sentence.tokens.iter().map(|token| token.text).collect())
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
+    /// tokenize_with_offsets(self, x)
+    ///
+    /// Tokenize a string into sentences of words, with their positions
+    ///
+    /// Parameters
+    /// ----------
+    /// x : str
+    ///    the string to tokenize
+    ///
+    /// Returns
+    /// -------
+    /// sentences : List[List[Tuple[str, int, int]]]
+    ///    words of each sentence, with their start and end character offsets
+    ///    in the document, such that ``x[start:end] == token``
+    fn tokenize_with_offsets<'py>(&self, py: Python<'py>, x: &str) -> PyResult<&'py PyList> {
+        let res: Vec<Vec<(&str, usize, usize)>> = self
+            .inner
+            .tokenize_sentences_with_char_offsets(x)
+            .map(|sentence| {
+                sentence
+                    .tokens
+                    .iter()
+                    .map(|token| (token.text, token.start, token.end))
+                    .collect()
+            })
+            .collect();
+        let list = PyList::new(py, res);
+        Ok(list)
+    }
+
+    /// get_params(self, x)
+    ///
+    /// Get parameters for this estimator.
+    ///
+    /// Returns
+    /// -------
+    /// params : mapping of string to any
+    ///          Parameter names mapped to their values.
+    fn get_params<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let params = PyDict::new(py);
+        params.set_item("sentence_tokenizer", &self.sentence_tokenizer)?;
+        params.set_item("word_tokenizer", &self.word_tokenizer)?;
+        Ok(params)
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok((&self.sentence_tokenizer, &self.word_tokenizer).to_object(py))
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let (sentence_tokenizer, word_tokenizer): (Option<&PyAny>, Option<&PyAny>) =
+            state.extract(py)?;
+        *self = DocumentTokenizer::from_tokenizers(py, sentence_tokenizer, word_tokenizer)?;
+        Ok(())
+    }
+}
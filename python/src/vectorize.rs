@@ -6,31 +6,204 @@
 
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
+use pyo3::types::PyBytes;
 use pyo3::types::PyIterator;
 
-use ndarray::arr1;
-use numpy::{IntoPyArray, PyArray1};
+use ndarray::{arr1, Array1};
+use numpy::{IntoPyArray, PyArray1, TypeNum};
+use serde::{Deserialize, Serialize};
 use sprs::CsMat;
+use std::borrow::Cow;
+use vtext::persistence::Format;
+use vtext::tokenize::{
+    Analyzer, CaseFolding, CharacterTokenizer, CharacterTokenizerParams, CharacterWbAnalyzer,
+    CharacterWbAnalyzerParams, RegexpTokenizer, StopWordFilter, StopWordFilterParams,
+};
+use vtext::vectorize::{HashFunction, Norm};
 
-type PyCsrArray = (Py<PyArray1<i32>>, Py<PyArray1<i32>>, Py<PyArray1<i32>>);
+type PyCsrArray<N> = (Py<PyArray1<i32>>, Py<PyArray1<i32>>, Py<PyArray1<N>>);
 
-fn iterable_to_collection(text: PyIterator) -> PyResult<Vec<String>> {
-    // This should not be necessary, ideally PyIterator should be converted
-    // to a Rust iterator
+/// Iterator over the documents of a Python iterable
+///
+/// Iteration stops at the first Python exception, which must then
+/// be checked with `PyDocumentIterator::check`.
+struct PyDocumentIterator<'p> {
+    inner: PyIterator<'p>,
+    error: Option<PyErr>,
+}
+
+impl<'p> PyDocumentIterator<'p> {
+    fn new(py: Python<'p>, x: &'p PyObject) -> PyResult<Self> {
+        Ok(PyDocumentIterator {
+            inner: PyIterator::from_object(py, x)?,
+            error: None,
+        })
+    }
+
+    /// Raise the exception that interrupted the iteration, if any
+    fn check(self) -> PyResult<()> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'p> Iterator for PyDocumentIterator<'p> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.inner.next()?.and_then(PyAny::extract::<String>) {
+            Ok(doc) => Some(doc),
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+}
+
+/// Analyzer of the Python vectorizers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum VectorizerAnalyzer {
+    Word(RegexpTokenizer),
+    Char(CharacterTokenizer),
+    CharWb(CharacterWbAnalyzer),
+}
+
+impl Default for VectorizerAnalyzer {
+    fn default() -> VectorizerAnalyzer {
+        VectorizerAnalyzer::Word(RegexpTokenizer::default())
+    }
+}
+
+impl Analyzer for VectorizerAnalyzer {
+    fn analyze<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a> {
+        match self {
+            VectorizerAnalyzer::Word(tokenizer) => tokenizer.analyze(text),
+            VectorizerAnalyzer::Char(tokenizer) => tokenizer.analyze(text),
+            VectorizerAnalyzer::CharWb(analyzer) => analyzer.analyze(text),
+        }
+    }
+}
+
+/// Build the analyzer and the word n-gram range of a vectorizer
+///
+/// For character analyzers, `ngram_range` applies to character n-grams.
+fn build_analyzer(
+    analyzer: &str,
+    ngram_range: (usize, usize),
+) -> PyResult<(VectorizerAnalyzer, (usize, usize))> {
+    let (min_n, max_n) = ngram_range;
+    match analyzer {
+        "word" => Ok((VectorizerAnalyzer::default(), ngram_range)),
+        "char" => {
+            let tokenizer = CharacterTokenizerParams::default()
+                .ngram_range(min_n, max_n)
+                .build()?;
+            Ok((VectorizerAnalyzer::Char(tokenizer), (1, 1)))
+        }
+        "char_wb" => {
+            let analyzer = CharacterWbAnalyzerParams::default()
+                .ngram_range(min_n, max_n)
+                .build()?;
+            Ok((VectorizerAnalyzer::CharWb(analyzer), (1, 1)))
+        }
+        _ => Err(PyErr::new::<pyo3::exceptions::ValueError, _>(format!(
+            "analyzer={} must be one of 'word', 'char', 'char_wb'",
+            analyzer
+        ))),
+    }
+}
+
+/// Parse the `norm` parameter of vectorizers
+fn parse_norm(norm: Option<&str>) -> PyResult<Norm> {
+    match norm {
+        None => Ok(Norm::None),
+        Some("l1") => Ok(Norm::L1),
+        Some("l2") => Ok(Norm::L2),
+        Some(norm) => Err(PyErr::new::<pyo3::exceptions::ValueError, _>(format!(
+            "norm={} must be one of 'l1', 'l2', None",
+            norm
+        ))),
+    }
+}
+
+/// Parse the `lowercase` and `case_folding` parameters of vectorizers
+///
+/// `case_folding`, if provided, takes precedence over `lowercase`.
+fn parse_case_folding(lowercase: bool, case_folding: Option<&str>) -> PyResult<CaseFolding> {
+    match case_folding {
+        None if lowercase => Ok(CaseFolding::Lowercase),
+        None => Ok(CaseFolding::None),
+        Some("none") => Ok(CaseFolding::None),
+        Some("ascii") => Ok(CaseFolding::Ascii),
+        Some("lowercase") => Ok(CaseFolding::Lowercase),
+        Some("casefold") => Ok(CaseFolding::Casefold),
+        Some(case_folding) => Err(PyErr::new::<pyo3::exceptions::ValueError, _>(format!(
+            "case_folding={} must be one of 'none', 'ascii', 'lowercase', 'casefold', None",
+            case_folding
+        ))),
+    }
+}
+
+/// Parse the `stop_words` parameter of vectorizers
+///
+/// A string is the language of a built-in stop word list, otherwise a list
+/// of stop words is expected.
+fn parse_stop_words(stop_words: Option<&PyAny>) -> PyResult<Option<StopWordFilter>> {
+    let stop_words = match stop_words {
+        None => return Ok(None),
+        Some(stop_words) => stop_words,
+    };
+    let filter = match stop_words.extract::<&str>() {
+        Ok(lang) => StopWordFilterParams::default().lang(lang).build()?,
+        Err(_) => StopWordFilterParams::default()
+            .stop_words(stop_words.extract::<Vec<String>>()?)
+            .build()?,
+    };
+    Ok(Some(filter))
+}
 
-    let collection: Result<Vec<_>, _> = text
-        .map(|doc| doc.and_then(PyAny::extract::<String>))
-        .collect();
-    Ok(collection?)
+/// Call a `*_as` method of a vectorizer, with the output type given by `dtype`
+///
+/// Evaluates to the CSR array as a Python object.
+macro_rules! vectorize_as {
+    ($py:expr, $dtype:expr, $estimator:expr, $method:ident, $documents:expr) => {
+        match $dtype {
+            "int32" => {
+                result_to_csr($py, $estimator.$method::<i32, _, _>($documents)?)?.into_py($py)
+            }
+            "int64" => {
+                result_to_csr($py, $estimator.$method::<i64, _, _>($documents)?)?.into_py($py)
+            }
+            "float32" => {
+                result_to_csr($py, $estimator.$method::<f32, _, _>($documents)?)?.into_py($py)
+            }
+            "float64" => {
+                result_to_csr($py, $estimator.$method::<f64, _, _>($documents)?)?.into_py($py)
+            }
+            _ => {
+                return Err(PyErr::new::<pyo3::exceptions::ValueError, _>(format!(
+                    "dtype={} must be one of int32, int64, float32, float64",
+                    $dtype
+                )))
+            }
+        }
+    };
 }
 
-fn result_to_csr(py: Python, x: CsMat<i32>) -> PyResult<PyCsrArray> {
-    // TODO: 1. use slices directly instead of creating new arrays
-    //       2. Possibly avoid casing
-    //          https://github.com/rust-ndarray/ndarray/issues/493#issuecomment-424043912
-    let indices = arr1(x.indices()).mapv(|elem| elem as i32);
-    let indptr = arr1(x.indptr()).mapv(|elem| elem as i32);
-    let data = arr1(x.data());
+fn result_to_csr<N: TypeNum>(py: Python, x: CsMat<N>) -> PyResult<PyCsrArray<N>> {
+    // TODO: Possibly avoid casing indices
+    //       https://github.com/rust-ndarray/ndarray/issues/493#issuecomment-424043912
+    let (indptr, indices, data) = x.into_raw_storage();
+    let indices = arr1(&indices).mapv(|elem| elem as i32);
+    let indptr = arr1(&indptr).mapv(|elem| elem as i32);
+    // data is moved to the numpy array without copying
+    let data = Array1::from(data);
 
     Ok((
         indices.into_pyarray(py).to_owned(),
@@ -41,47 +214,110 @@ fn result_to_csr(py: Python, x: CsMat<i32>) -> PyResult<PyCsrArray> {
 
 #[pyclass]
 pub struct _HashingVectorizerWrapper {
-    inner: vtext::vectorize::HashingVectorizer<vtext::tokenize::RegexpTokenizer>,
+    inner: vtext::vectorize::HashingVectorizer<VectorizerAnalyzer>,
 }
 
 #[pymethods]
 impl _HashingVectorizerWrapper {
     #[new]
-    #[args(n_jobs = 1)]
-    fn new(n_jobs: usize) -> PyResult<Self> {
-        let tokenizer = vtext::tokenize::RegexpTokenizer::default();
-        let estimator = vtext::vectorize::HashingVectorizerParams::default()
+    #[args(
+        n_jobs = 1,
+        analyzer = "\"word\"",
+        ngram_range = "(1, 1)",
+        ngram_separator = "\" \"",
+        lowercase = true,
+        case_folding = "None",
+        stop_words = "None",
+        n_features = 1048576,
+        alternate_sign = false,
+        seed = 0,
+        hash_function = "\"seahash\"",
+        binary = false,
+        norm = "None"
+    )]
+    fn new(
+        n_jobs: usize,
+        analyzer: &str,
+        ngram_range: (usize, usize),
+        ngram_separator: &str,
+        lowercase: bool,
+        case_folding: Option<&str>,
+        stop_words: Option<&PyAny>,
+        n_features: u64,
+        alternate_sign: bool,
+        seed: u32,
+        hash_function: &str,
+        binary: bool,
+        norm: Option<&str>,
+    ) -> PyResult<Self> {
+        let (tokenizer, ngram_range) = build_analyzer(analyzer, ngram_range)?;
+        let hash_function = match hash_function {
+            "seahash" => HashFunction::SeaHash,
+            "murmurhash3" => HashFunction::Murmur3,
+            _ => {
+                return Err(PyErr::new::<pyo3::exceptions::ValueError, _>(format!(
+                    "hash_function={} must be one of 'seahash', 'murmurhash3'",
+                    hash_function
+                )))
+            }
+        };
+        let mut params = vtext::vectorize::HashingVectorizerParams::default();
+        if let Some(stop_words) = parse_stop_words(stop_words)? {
+            params.stop_words(stop_words);
+        }
+        let estimator = params
             .tokenizer(tokenizer)
+            .ngram_range(ngram_range.0, ngram_range.1)
+            .ngram_separator(ngram_separator)
+            .case_folding(parse_case_folding(lowercase, case_folding)?)
+            .n_features(n_features)
+            .alternate_sign(alternate_sign)
+            .seed(seed)
+            .hash_function(hash_function)
+            .binary(binary)
+            .norm(parse_norm(norm)?)
             .n_jobs(n_jobs)
             .build()?;
 
         Ok(_HashingVectorizerWrapper { inner: estimator })
     }
 
-    fn transform(&mut self, py: Python, x: PyObject) -> PyResult<PyCsrArray> {
-        let text = PyIterator::from_object(py, &x)?;
+    #[args(dtype = "\"int32\"")]
+    fn transform(&mut self, py: Python, x: PyObject, dtype: &str) -> PyResult<PyObject> {
+        let mut documents = PyDocumentIterator::new(py, &x)?;
 
-        let collection = iterable_to_collection(text)?;
+        let x = vectorize_as!(py, dtype, self.inner, transform_as, &mut documents);
+        documents.check()?;
 
-        let x = self.inner.fit_transform(&collection);
-
-        result_to_csr(py, x)
+        Ok(x)
     }
 }
 
 #[pyclass]
 pub struct _CountVectorizerWrapper {
-    inner: vtext::vectorize::CountVectorizer<vtext::tokenize::RegexpTokenizer>,
+    inner: vtext::vectorize::CountVectorizer<VectorizerAnalyzer>,
 }
 
 #[pymethods]
 impl _CountVectorizerWrapper {
     #[new]
-    #[args(n_jobs = 1)]
-    fn new(n_jobs: usize) -> PyResult<Self> {
-        let tokenizer = vtext::tokenize::RegexpTokenizer::default();
+    #[args(
+        n_jobs = 1,
+        analyzer = "\"word\"",
+        ngram_range = "(1, 1)",
+        binary = false
+    )]
+    fn new(
+        n_jobs: usize,
+        analyzer: &str,
+        ngram_range: (usize, usize),
+        binary: bool,
+    ) -> PyResult<Self> {
+        let (tokenizer, ngram_range) = build_analyzer(analyzer, ngram_range)?;
         let estimator = vtext::vectorize::CountVectorizerParams::default()
             .tokenizer(tokenizer)
+            .ngram_range(ngram_range.0, ngram_range.1)
+            .binary(binary)
             .n_jobs(n_jobs)
             .build()?;
 
@@ -89,36 +325,66 @@ impl _CountVectorizerWrapper {
     }
 
     fn fit(&mut self, py: Python, x: PyObject) -> PyResult<()> {
-        let text = PyIterator::from_object(py, &x)?;
-
-        let collection = iterable_to_collection(text)?;
+        let mut documents = PyDocumentIterator::new(py, &x)?;
 
-        self.inner.fit(&collection);
+        self.inner.fit(&mut documents);
+        documents.check()?;
         Ok(())
     }
 
     fn get_n_features(&self) -> PyResult<usize> {
-        let n_features = self.inner.vocabulary.len();
+        let n_features = self.inner.n_features();
         Ok(n_features)
     }
 
-    fn transform(&mut self, py: Python, x: PyObject) -> PyResult<PyCsrArray> {
-        let text = PyIterator::from_object(py, &x)?;
+    fn get_feature_names(&self) -> PyResult<Vec<String>> {
+        Ok(self.inner.get_feature_names())
+    }
+
+    /// Terms with a non zero value in each row of a CSR matrix
+    fn inverse_transform(
+        &self,
+        shape: (usize, usize),
+        indptr: &PyArray1<i64>,
+        indices: &PyArray1<i64>,
+        data: &PyArray1<f64>,
+    ) -> PyResult<Vec<Vec<String>>> {
+        let indptr: Vec<usize> = indptr.as_slice()?.iter().map(|x| *x as usize).collect();
+        let indices: Vec<usize> = indices.as_slice()?.iter().map(|x| *x as usize).collect();
+        let x = CsMat::try_new(shape, indptr, indices, data.as_slice()?.to_vec())
+            .map_err(|err| PyErr::new::<pyo3::exceptions::ValueError, _>(format!("{}", err)))?;
+        Ok(self.inner.inverse_transform(&x)?)
+    }
 
-        let collection = iterable_to_collection(text)?;
+    #[args(dtype = "\"int32\"")]
+    fn transform(&mut self, py: Python, x: PyObject, dtype: &str) -> PyResult<PyObject> {
+        let mut documents = PyDocumentIterator::new(py, &x)?;
 
-        let x = self.inner.transform(&collection);
+        let x = vectorize_as!(py, dtype, self.inner, transform_as, &mut documents);
+        documents.check()?;
 
-        result_to_csr(py, x)
+        Ok(x)
     }
 
-    fn fit_transform(&mut self, py: Python, x: PyObject) -> PyResult<PyCsrArray> {
-        let text = PyIterator::from_object(py, &x)?;
+    #[args(dtype = "\"int32\"")]
+    fn fit_transform(&mut self, py: Python, x: PyObject, dtype: &str) -> PyResult<PyObject> {
+        let mut documents = PyDocumentIterator::new(py, &x)?;
 
-        let collection = iterable_to_collection(text)?;
+        let x = vectorize_as!(py, dtype, self.inner, fit_transform_as, &mut documents);
+        documents.check()?;
 
-        let x = self.inner.fit_transform(&collection);
+        Ok(x)
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let mut state: Vec<u8> = Vec::new();
+        vtext::persistence::save(&self.inner, &mut state, Format::Binary)?;
+        Ok(PyBytes::new(py, &state).to_object(py))
+    }
 
-        result_to_csr(py, x)
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        let state = state.extract::<&PyBytes>(py)?;
+        self.inner = vtext::persistence::load(state.as_bytes(), Format::Binary)?;
+        Ok(())
     }
 }
@@ -12,7 +12,13 @@ from vtext.tokenize import (
     UnicodeWordTokenizer,
     VTextTokenizer,
 )
-from vtext.tokenize_sentence import UnicodeSentenceTokenizer, PunctuationTokenizer
+from vtext.tokenize_sentence import (
+    UnicodeSentenceTokenizer,
+    PunctuationTokenizer,
+    PunktSentenceTokenizer,
+    ParagraphTokenizer,
+    DocumentTokenizer,
+)
 from vtext.stem import SnowballStemmer
 
 
@@ -23,7 +29,13 @@ TOKENIZERS = [
     VTextTokenizer,
 ]
 
-SENTENCE_TOKENIZERS = [UnicodeSentenceTokenizer, PunctuationTokenizer]
+SENTENCE_TOKENIZERS = [
+    UnicodeSentenceTokenizer,
+    PunctuationTokenizer,
+    PunktSentenceTokenizer,
+    ParagraphTokenizer,
+    DocumentTokenizer,
+]
 STEMMERS = [SnowballStemmer]
 
 
@@ -68,6 +68,10 @@ def test_character_tokenizer():
         "an't",
     ]
 
+    tokenizer = CharacterTokenizer(ngram_range=(1, 2))
+    assert tokenizer.tokenize("été") == ["é", "ét", "t", "té", "é"]
+    assert tokenizer.get_params() == {"window_size": 4, "ngram_range": (1, 2)}
+
 
 @hypothesis.given(st.text())
 @pytest.mark.parametrize(
@@ -85,6 +89,25 @@ def test_tokenize_edge_cases(tokenizer, txt):
     tokenizer.tokenize(txt)
 
 
+@hypothesis.given(st.text())
+@pytest.mark.parametrize(
+    "tokenizer",
+    [
+        RegexpTokenizer(),
+        CharacterTokenizer(),
+        UnicodeWordTokenizer(),
+        VTextTokenizer("en"),
+        VTextTokenizer("fr"),
+    ],
+    ids=_pytest_ids,
+)
+def test_tokenize_with_offsets(tokenizer, txt):
+    tokens = tokenizer.tokenize_with_offsets(txt)
+    assert [token for token, _, _ in tokens] == tokenizer.tokenize(txt)
+    for token, start, end in tokens:
+        assert txt[start:end] == token
+
+
 @pytest.mark.parametrize(
     "tokenizer, expected",
     [
@@ -4,14 +4,28 @@
 # <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
 # modified, or distributed except according to those terms.
 
+import pickle
+
 import pytest
 import hypothesis
 import hypothesis.strategies as st
 
-from vtext.tokenize import BaseTokenizer
-from vtext.tokenize_sentence import UnicodeSentenceTokenizer, PunctuationTokenizer
+from vtext.tokenize import BaseTokenizer, RegexpTokenizer
+from vtext.tokenize_sentence import (
+    UnicodeSentenceTokenizer,
+    PunctuationTokenizer,
+    PunktSentenceTokenizer,
+    ParagraphTokenizer,
+    DocumentTokenizer,
+)
 
-TOKENIZERS = [UnicodeSentenceTokenizer, PunctuationTokenizer]
+TOKENIZERS = [
+    UnicodeSentenceTokenizer,
+    PunctuationTokenizer,
+    PunktSentenceTokenizer,
+    ParagraphTokenizer,
+    DocumentTokenizer,
+]
 
 
 def _pytest_ids(x):
@@ -48,18 +62,158 @@ def test_punctuation_sentence_tokenizer():
 
 @hypothesis.given(st.text())
 @pytest.mark.parametrize(
-    "tokenizer", [UnicodeSentenceTokenizer(), PunctuationTokenizer()], ids=_pytest_ids,
+    "tokenizer",
+    [
+        UnicodeSentenceTokenizer(),
+        PunctuationTokenizer(),
+        PunktSentenceTokenizer(),
+        ParagraphTokenizer(),
+        ParagraphTokenizer(split_lines=True),
+    ],
+    ids=_pytest_ids,
 )
 def test_tokenize_edge_cases(tokenizer, txt):
     tokens = tokenizer.tokenize(txt)
     assert len("".join(tokens)) == len(txt)
 
 
+def test_sentence_tokenize_with_offsets():
+    tokenizer = PunctuationTokenizer()
+    assert tokenizer.tokenize_with_offsets("Él está aquí. ¿Y tú?") == [
+        ("Él está aquí. ", 0, 14),
+        ("¿Y tú?", 14, 20),
+    ]
+
+
+def test_punctuation_tokenizer_abbreviations():
+    tokenizer = PunctuationTokenizer(lang="en", abbreviations=["circ"])
+    assert tokenizer.tokenize("Dr. Smith was born circ. 1900 in Rome. OK") == [
+        "Dr. Smith was born circ. 1900 in Rome. ",
+        "OK",
+    ]
+
+
+def test_punctuation_tokenizer_terminators():
+    tokenizer = PunctuationTokenizer(punctuation=["?!", "...", "!", "?", "."])
+    assert tokenizer.tokenize('He asked "Really?!" Well... (Yes.) OK') == [
+        'He asked "Really?!" ',
+        "Well... ",
+        "(Yes.) ",
+        "OK",
+    ]
+    tokenizer = PunctuationTokenizer(punctuation=[], punctuation_pattern=r"\n\n+")
+    assert tokenizer.tokenize("First. Paragraph\n\nSecond one") == [
+        "First. Paragraph\n\n",
+        "Second one",
+    ]
+
+
+def test_punkt_sentence_tokenizer():
+    names = ["Smith", "Jones", "Brown", "Taylor", "Wilson"]
+    documents = []
+    for idx in range(50):
+        a, b = names[idx % 5], names[(idx + 1) % 5]
+        documents.append(f"Dr. {a} met {b}. Then {b} was happy to see Dr. {a}.")
+    tokenizer = PunktSentenceTokenizer()
+    assert tokenizer.tokenize("I met Dr. Brown. Then we left.") == [
+        "I met Dr. ",
+        "Brown. ",
+        "Then we left.",
+    ]
+    tokenizer.fit(documents)
+    assert tokenizer.get_abbreviations() == ["dr"]
+    assert tokenizer.tokenize("I met Dr. Brown. Then we left.") == [
+        "I met Dr. Brown. ",
+        "Then we left.",
+    ]
+
+
+def test_paragraph_tokenizer():
+    text = "A hard-wrapped\r\nparagraph.\r\n\r\nAnother one.\u2029Last"
+    tokenizer = ParagraphTokenizer()
+    assert tokenizer.tokenize(text) == [
+        "A hard-wrapped\r\nparagraph.\r\n\r\n",
+        "Another one.\u2029",
+        "Last",
+    ]
+    tokenizer = ParagraphTokenizer(split_lines=True)
+    assert tokenizer.tokenize_with_offsets(text)[:2] == [
+        ("A hard-wrapped\r\n", 0, 16),
+        ("paragraph.\r\n\r\n", 16, 30),
+    ]
+
+
+def test_document_tokenizer():
+    text = "Él está aquí. ¿Y tú? Sí!"
+    tokenizer = DocumentTokenizer()
+    assert tokenizer.tokenize(text) == [
+        ["Él", "está", "aquí", "."],
+        ["¿", "Y", "tú", "?"],
+        ["Sí", "!"],
+    ]
+    sentences = tokenizer.tokenize_with_offsets(text)
+    assert sentences[1][2] == ("tú", 17, 19)
+    for sentence in sentences:
+        for token, start, end in sentence:
+            assert text[start:end] == token
+
+    tokenizer = DocumentTokenizer(
+        sentence_tokenizer=ParagraphTokenizer(),
+        word_tokenizer=RegexpTokenizer(pattern=r"\w+"),
+    )
+    assert tokenizer.tokenize_with_offsets("a b.\n\nc") == [
+        [("a", 0, 1), ("b", 2, 3)],
+        [("c", 6, 7)],
+    ]
+    assert tokenizer.tokenize("") == []
+    assert tokenizer.get_params()["sentence_tokenizer"].get_params() == {
+        "split_lines": False
+    }
+
+    # sentence and word tokenizers are not interchangeable
+    with pytest.raises(TypeError, match="sentence_tokenizer"):
+        DocumentTokenizer(sentence_tokenizer=RegexpTokenizer())
+    with pytest.raises(TypeError, match="word_tokenizer"):
+        DocumentTokenizer(word_tokenizer=ParagraphTokenizer())
+
+
+def test_document_tokenizer_pickle():
+    text = "a b.\n\nc"
+    tokenizer = DocumentTokenizer(
+        sentence_tokenizer=ParagraphTokenizer(),
+        word_tokenizer=RegexpTokenizer(pattern=r"\w+"),
+    )
+    expected = tokenizer.tokenize_with_offsets(text)
+    tokenizer = pickle.loads(pickle.dumps(tokenizer))
+    assert tokenizer.tokenize_with_offsets(text) == expected
+    assert tokenizer.get_params()["word_tokenizer"].get_params() == {
+        "pattern": r"\w+"
+    }
+
+
 @pytest.mark.parametrize(
     "tokenizer, expected",
     [
         (UnicodeSentenceTokenizer(), {}),
-        (PunctuationTokenizer(), {"punctuation": [".", "!", "?"]}),
+        (ParagraphTokenizer(), {"split_lines": False}),
+        (
+            PunctuationTokenizer(),
+            {
+                "punctuation": [".", "!", "?"],
+                "punctuation_pattern": None,
+                "lang": None,
+                "abbreviations": [],
+            },
+        ),
+        (
+            PunktSentenceTokenizer(),
+            {
+                "abbreviations": [],
+                "abbreviation_threshold": 0.3,
+                "collocation_threshold": 7.88,
+                "sentence_starter_threshold": 30.0,
+            },
+        ),
     ],
     ids=_pytest_ids,
 )
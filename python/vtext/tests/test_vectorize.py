@@ -6,7 +6,8 @@
 
 import pickle
 
-from numpy.testing import assert_array_equal
+import numpy as np
+from numpy.testing import assert_array_equal, assert_allclose
 
 import pytest
 
@@ -47,6 +48,17 @@ def test_pickle_vectorizers(Estimator):
     pickle.loads(out)
 
 
+def test_pickle_fitted_count_vectorizer():
+    text = ["some sentence", "a different sentence"]
+    vect = CountVectorizer()
+    X = vect.fit_transform(text)
+
+    vect2 = pickle.loads(pickle.dumps(vect))
+    X2 = vect2.transform(text)
+    assert_array_equal(X.indices, X2.indices)
+    assert_array_equal(X.data, X2.data)
+
+
 @pytest.mark.parametrize("Estimator", [HashingVectorizer, CountVectorizer])
 def test_vectorizers_n_jobs(Estimator):
     """Check that parallel feature ingestion works"""
@@ -58,3 +70,170 @@ def test_vectorizers_n_jobs(Estimator):
 
     with pytest.raises(ValueError, match="n_jobs=0 must be a integer >= 1"):
         Estimator(n_jobs=0).fit(text)
+
+
+@pytest.mark.parametrize("Estimator", [HashingVectorizer, CountVectorizer])
+def test_vectorizers_generator_input(Estimator):
+    text = ["some sentence", "a different sentence"]
+    X_ref = Estimator().fit_transform(text)
+
+    X = Estimator().fit_transform(doc for doc in text)
+    assert_array_equal(X.indices, X_ref.indices)
+    assert_array_equal(X.data, X_ref.data)
+
+    with pytest.raises(TypeError):
+        Estimator().fit_transform(doc for doc in ["some sentence", 1])
+
+
+@pytest.mark.parametrize("Estimator", [HashingVectorizer, CountVectorizer])
+@pytest.mark.parametrize("analyzer", ["word", "char", "char_wb"])
+def test_vectorizers_analyzer(Estimator, analyzer):
+    sklearn = pytest.importorskip("sklearn.feature_extraction.text")
+    text = ["some sentence", "a different sentence!"]
+
+    X = Estimator(analyzer=analyzer, ngram_range=(1, 3)).fit_transform(text)
+    X_ref = getattr(sklearn, "CountVectorizer")(
+        analyzer=analyzer, ngram_range=(1, 3)
+    ).fit_transform(text)
+    # counts of n-grams match scikit-learn, up to hash collisions
+    assert X.sum() == X_ref.sum()
+
+    with pytest.raises(ValueError, match="analyzer="):
+        Estimator(analyzer="unknown").fit(text)
+
+
+@pytest.mark.parametrize("alternate_sign", [False, True])
+def test_hashing_vectorizer_murmurhash3(alternate_sign):
+    sklearn = pytest.importorskip("sklearn.feature_extraction.text")
+    text = ["some sentence", "a different sentence, with other words"]
+
+    X = HashingVectorizer(
+        hash_function="murmurhash3", alternate_sign=alternate_sign
+    ).fit_transform(text)
+    X_ref = sklearn.HashingVectorizer(
+        alternate_sign=alternate_sign, norm=None
+    ).fit_transform(text)
+    assert_array_equal(X.toarray(), X_ref.toarray())
+
+
+def test_hashing_vectorizer_seed():
+    text = ["some sentence", "a different sentence, with other words"]
+
+    for hash_function in ["seahash", "murmurhash3"]:
+        X = HashingVectorizer(hash_function=hash_function).fit_transform(text)
+        X_0 = HashingVectorizer(hash_function=hash_function, seed=0).fit_transform(text)
+        X_1 = HashingVectorizer(hash_function=hash_function, seed=1).fit_transform(text)
+        assert_array_equal(X.indices, X_0.indices)
+        assert set(X.indices) != set(X_1.indices)
+        assert X_1.sum() == X.sum()
+
+    for seed in [-1, 2 ** 32, 1.5]:
+        with pytest.raises(ValueError, match="seed="):
+            HashingVectorizer(seed=seed).fit_transform(text)
+
+
+def test_hashing_vectorizer_n_features():
+    text = ["some sentence", "a different sentence, with other words"]
+
+    X = HashingVectorizer(n_features=16).fit_transform(text)
+    assert X.shape == (2, 16)
+    assert X.indices.max() < 16
+    # colliding features are summed
+    assert X.sum() == 7
+
+    for n_features in [0, 2 ** 31 + 1, 1.5]:
+        with pytest.raises(ValueError, match="n_features="):
+            HashingVectorizer(n_features=n_features).fit_transform(text)
+
+
+@pytest.mark.parametrize("Estimator", [HashingVectorizer, CountVectorizer])
+@pytest.mark.parametrize("dtype", [np.int32, np.int64, np.float32, np.float64])
+def test_vectorizers_binary_dtype(Estimator, dtype):
+    text = ["some sentence sentence", "a different sentence, with other words"]
+
+    X = Estimator(binary=True, dtype=dtype).fit_transform(text)
+    assert X.dtype == dtype
+    assert_array_equal(X.data, 1)
+
+    with pytest.raises(ValueError, match="dtype="):
+        Estimator(dtype=np.int8).fit_transform(text)
+
+
+def test_hashing_vectorizer_norm():
+    text = ["some sentence sentence", "a different sentence, with other words"]
+
+    X = HashingVectorizer(norm="l2", dtype=np.float64).fit_transform(text)
+    assert_allclose(np.asarray(X.multiply(X).sum(axis=1)).ravel(), 1.0)
+
+    X = HashingVectorizer(norm="l1", dtype=np.float32).fit_transform(text)
+    assert_allclose(np.asarray(abs(X).sum(axis=1)).ravel(), 1.0, rtol=1e-6)
+
+    with pytest.raises(ValueError, match="requires a floating point dtype"):
+        HashingVectorizer(norm="l2").fit_transform(text)
+    with pytest.raises(ValueError, match="norm="):
+        HashingVectorizer(norm="l3", dtype=np.float64).fit_transform(text)
+
+
+def test_hashing_vectorizer_sklearn_defaults():
+    # scikit-learn's HashingVectorizer defaults, with reference indices from
+    # scikit-learn 0.20.0
+    text = ["the moon in the sky", "The sky is blue"]
+    X = HashingVectorizer(
+        hash_function="murmurhash3", alternate_sign=True, norm="l2", dtype=np.float64
+    ).fit_transform(text)
+
+    assert_array_equal(X.indptr, [0, 4, 8])
+    assert_array_equal(
+        X.indices, [268391, 286878, 720286, 828689, 144749, 268391, 286878, 790269]
+    )
+    a, b = 1 / np.sqrt(7), 1 / 2
+    assert_allclose(X.data, [-a, -2 * a, -a, a, b, -b, -b, -b])
+
+
+def test_hashing_vectorizer_preprocessing():
+    text = ["the moon in the sky", "The sky is blue"]
+    X = HashingVectorizer(
+        hash_function="murmurhash3",
+        n_features=16,
+        lowercase=False,
+        stop_words="en",
+        ngram_range=(1, 2),
+        ngram_separator="_",
+    ).fit_transform(text)
+
+    # "The" is not lowercased, so it is not removed as a stop word
+    assert_array_equal(
+        X.toarray(),
+        [
+            [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0],
+            [0, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1],
+        ],
+    )
+
+    X = HashingVectorizer(stop_words=["sky", "blue"]).fit_transform(text)
+    assert X.sum() == 6
+    X = HashingVectorizer(case_folding="casefold").fit_transform(["STRASSE straße"])
+    assert_array_equal(X.data, [2])
+
+    with pytest.raises(ValueError, match="case_folding="):
+        HashingVectorizer(case_folding="upper").fit_transform(text)
+
+
+def test_count_vectorizer_inverse_transform():
+    text = ["the moon in the sky", "the sky is blue"]
+    vect = CountVectorizer()
+    X = vect.fit_transform(text)
+
+    assert vect.get_feature_names() == ["blue", "in", "is", "moon", "sky", "the"]
+
+    terms = vect.inverse_transform(X)
+    assert len(terms) == 2
+    assert_array_equal(terms[0], ["in", "moon", "sky", "the"])
+    assert_array_equal(terms[1], ["blue", "is", "sky", "the"])
+    # dense arrays are supported
+    assert_array_equal(vect.inverse_transform(X.toarray())[1], terms[1])
+
+    with pytest.raises(ValueError, match="features"):
+        vect.inverse_transform(X[:, :3])
+    with pytest.raises(ValueError, match="fitted"):
+        CountVectorizer().get_feature_names()
@@ -4,7 +4,19 @@
 # <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
 # modified, or distributed except according to those terms.
 
-from ._lib import UnicodeSentenceTokenizer, PunctuationTokenizer
+from ._lib import (
+    UnicodeSentenceTokenizer,
+    PunctuationTokenizer,
+    PunktSentenceTokenizer,
+    ParagraphTokenizer,
+    DocumentTokenizer,
+)
 
 
-__all__ = ["UnicodeSentenceTokenizer", "PunctuationTokenizer"]
+__all__ = [
+    "UnicodeSentenceTokenizer",
+    "PunctuationTokenizer",
+    "PunktSentenceTokenizer",
+    "ParagraphTokenizer",
+    "DocumentTokenizer",
+]
@@ -19,6 +19,17 @@ except ImportError:
 
 __all__ = ["CountVectorizer", "HashingVectorizer"]
 
+_SUPPORTED_DTYPES = ("int32", "int64", "float32", "float64")
+
+
+def _validate_dtype(dtype):
+    if np.dtype(dtype).name not in _SUPPORTED_DTYPES:
+        raise ValueError(
+            "dtype={} must be one of {}".format(
+                np.dtype(dtype).name, ", ".join(_SUPPORTED_DTYPES)
+            )
+        )
+
 
 class HashingVectorizer(BaseEstimator):
     """Convert a collection of text documents to a matrix of token occurrences
@@ -37,20 +48,54 @@ class HashingVectorizer(BaseEstimator):
     Parameters
     ----------
 
-    analyzer : string, {'word'}
+    analyzer : string, {'word', 'char', 'char_wb'}
         Whether the feature should be made of word or character n-grams.
-
-        Currently only the "word" tokenizer is implemented.
+        Option 'char_wb' creates character n-grams only from text inside
+        word boundaries; n-grams at the edges of words are padded with space.
+    ngram_range : tuple (min_n, max_n), default=(1, 1)
+        The lower and upper boundary of the range of n-values for different
+        n-grams to be extracted. For the 'char' and 'char_wb' analyzers, these are
+        character n-grams.
+    ngram_separator : string, default=' '
+        String used to join the tokens of word n-grams.
+    lowercase : boolean, default=True
+        Convert all characters to lowercase before tokenizing.
+    case_folding : string, {'none', 'ascii', 'lowercase', 'casefold'}, default=None
+        Case folding applied before tokenizing. 'casefold' applies full Unicode
+        case folding, as ``str.casefold``. If provided, ``lowercase`` is ignored.
+    stop_words : string or list, default=None
+        If a string, the language of a built-in stop word list (e.g. 'en').
+        If a list, the stop words to remove. Stop words are removed from the
+        tokens before n-grams are computed.
+    n_features : integer, default=(2 ** 20)
+        The number of features (columns) in the output matrices. Small numbers
+        of features are likely to cause hash collisions, but large numbers
+        will cause larger coefficient dimensions in linear learners.
+        Must be between 1 and 2 ** 31.
     binary : boolean, default=False
         If True, all non zero counts are set to 1. This is useful for discrete
         probabilistic models that model binary events rather than integer
         counts.
+    norm : {'l1', 'l2'}, default=None
+        Norm used to normalize term vectors. None for no normalization.
+        Requires a floating point ``dtype``.
+    alternate_sign : boolean, default=False
+        When True, an alternating sign is added to the features as to
+        approximately conserve the inner product in the hashed space even for
+        small n_features.
+    seed : int, default=0
+        Seed of the hash function. Features only match those of another
+        vectorizer (including a Rust ``HashingVectorizer``) with the same seed.
+    hash_function : string, {'seahash', 'murmurhash3'}, default='seahash'
+        Hash function used to compute feature indices. With 'murmurhash3' and
+        seed=0, features are the same as in scikit-learn's HashingVectorizer
+        with the same ``alternate_sign``.
     n_jobs : int, default=1
         number of threads to use for parallel feature extraction. n_jobs > 1,
         is faster, but uses more memory.
 
-        Note: currently any value n_jobs > 1 will use all available cores.
-    dtype : type, optional
+        Documents are processed in a dedicated pool of n_jobs threads.
+    dtype : type, {np.int32, np.int64, np.float32, np.float64}, default=np.int32
         Type of the matrix returned by fit_transform() or transform().
 
 
@@ -63,16 +108,43 @@ class HashingVectorizer(BaseEstimator):
     ...     'And this is the third one.',
     ...     'Is this the first document?',
     ... ]
-    >>> vectorizer = HashingVectorizer()
+    >>> vectorizer = HashingVectorizer(n_features=2 ** 4)
     >>> X = vectorizer.fit_transform(corpus)
     >>> print(X.shape)
     (4, 16)
 
     """
 
-    def __init__(self, *, analyzer="word", binary=False, n_jobs=1, dtype=np.int32):
+    def __init__(
+        self,
+        *,
+        analyzer="word",
+        ngram_range=(1, 1),
+        ngram_separator=" ",
+        lowercase=True,
+        case_folding=None,
+        stop_words=None,
+        n_features=(2 ** 20),
+        binary=False,
+        norm=None,
+        alternate_sign=False,
+        seed=0,
+        hash_function="seahash",
+        n_jobs=1,
+        dtype=np.int32
+    ):
         self.analyzer = analyzer
+        self.ngram_range = ngram_range
+        self.ngram_separator = ngram_separator
+        self.lowercase = lowercase
+        self.case_folding = case_folding
+        self.stop_words = stop_words
+        self.n_features = n_features
         self.binary = binary
+        self.norm = norm
+        self.alternate_sign = alternate_sign
+        self.seed = seed
+        self.hash_function = hash_function
         self.dtype = dtype
         self.n_jobs = n_jobs
 
@@ -91,12 +163,47 @@ class HashingVectorizer(BaseEstimator):
 
     def _validate_params(self):
 
-        if self.analyzer != "word":
-            raise NotImplementedError
+        if self.analyzer not in ("word", "char", "char_wb"):
+            raise ValueError(
+                "analyzer={} must be one of 'word', 'char', 'char_wb'".format(
+                    self.analyzer
+                )
+            )
 
         if not isinstance(self.n_jobs, int) or self.n_jobs < 1:
             raise ValueError("n_jobs={} must be a integer >= 1".format(self.n_jobs))
 
+        _validate_dtype(self.dtype)
+
+        if self.norm not in ("l1", "l2", None):
+            raise ValueError(
+                "norm={} must be one of 'l1', 'l2', None".format(self.norm)
+            )
+        if self.norm is not None and np.dtype(self.dtype).kind != "f":
+            raise ValueError(
+                "norm={} requires a floating point dtype, got {}".format(
+                    self.norm, np.dtype(self.dtype).name
+                )
+            )
+
+        if self.case_folding not in ("none", "ascii", "lowercase", "casefold", None):
+            raise ValueError(
+                "case_folding={} must be one of 'none', 'ascii', 'lowercase', "
+                "'casefold', None".format(self.case_folding)
+            )
+
+        if not isinstance(self.n_features, int) or not 1 <= self.n_features <= 2 ** 31:
+            raise ValueError(
+                "n_features={} must be an integer between 1 and 2 ** 31".format(
+                    self.n_features
+                )
+            )
+
+        if not isinstance(self.seed, int) or not 0 <= self.seed < 2 ** 32:
+            raise ValueError(
+                "seed={} must be an integer between 0 and 2 ** 32 - 1".format(self.seed)
+            )
+
     def fit(self, X, y=None):
         """Does nothing: this transformer is stateless.
 
@@ -111,7 +218,21 @@ class HashingVectorizer(BaseEstimator):
             )
 
         self._validate_params()
-        self._inner = _lib._HashingVectorizerWrapper(n_jobs=self.n_jobs)
+        self._inner = _lib._HashingVectorizerWrapper(
+            n_jobs=self.n_jobs,
+            analyzer=self.analyzer,
+            ngram_range=tuple(self.ngram_range),
+            ngram_separator=self.ngram_separator,
+            lowercase=self.lowercase,
+            case_folding=self.case_folding,
+            stop_words=self.stop_words,
+            n_features=self.n_features,
+            alternate_sign=self.alternate_sign,
+            seed=self.seed,
+            hash_function=self.hash_function,
+            binary=self.binary,
+            norm=self.norm,
+        )
 
         return self
 
@@ -141,12 +262,12 @@ class HashingVectorizer(BaseEstimator):
             # Initialize the wrapper
             self.fit(None)
 
-        indices, indptr, data = self._inner.transform(X)
-        if self.binary:
-            data.fill(1)
-
-        data = data.astype(self.dtype, copy=False)
-        return sp.csr_matrix((data, indices, indptr), shape=(len(X), 1048576))
+        indices, indptr, data = self._inner.transform(
+            X, dtype=np.dtype(self.dtype).name
+        )
+        return sp.csr_matrix(
+            (data, indices, indptr), shape=(len(indptr) - 1, self.n_features)
+        )
 
     def fit_transform(self, X, y=None):
         """Transform a sequence of documents to a document-term matrix.
@@ -181,10 +302,14 @@ class CountVectorizer(BaseEstimator):
 
     Parameters
     ----------
-    analyzer : string, {'word'}
+    analyzer : string, {'word', 'char', 'char_wb'}
         Whether the feature should be made of word or character n-grams.
-
-        Currently only `analyzer="word"` is implemented.
+        Option 'char_wb' creates character n-grams only from text inside
+        word boundaries; n-grams at the edges of words are padded with space.
+    ngram_range : tuple (min_n, max_n), default=(1, 1)
+        The lower and upper boundary of the range of n-values for different
+        n-grams to be extracted. For the 'char' and 'char_wb' analyzers, these are
+        character n-grams.
 
     binary : boolean, default=False
         If True, all non zero counts are set to 1. This is useful for discrete
@@ -194,9 +319,9 @@ class CountVectorizer(BaseEstimator):
         number of threads to use for parallel feature extraction. n_jobs > 1,
         is faster, but uses more memory.
 
-        Note: currently any value n_jobs > 1 will use all available cores.
+        Documents are processed in a dedicated pool of n_jobs threads.
 
-    dtype : type, optional
+    dtype : type, {np.int32, np.int64, np.float32, np.float64}, default=np.int64
         Type of the matrix returned by fit_transform() or transform().
 
     Attributes
@@ -207,7 +332,7 @@ class CountVectorizer(BaseEstimator):
 
     Examples
     --------
-    >>> from vtext.tokenize import CountVectorizer
+    >>> from vtext.vectorize import CountVectorizer
     >>> corpus = [
     ...     'This is the first document.',
     ...     'This document is the second document.',
@@ -230,8 +355,17 @@ class CountVectorizer(BaseEstimator):
 
     """
 
-    def __init__(self, *, analyzer="word", binary=False, n_jobs=1, dtype=np.int64):
+    def __init__(
+        self,
+        *,
+        analyzer="word",
+        ngram_range=(1, 1),
+        binary=False,
+        n_jobs=1,
+        dtype=np.int64
+    ):
         self.analyzer = analyzer
+        self.ngram_range = ngram_range
         self.binary = binary
         self.n_jobs = n_jobs
         self.dtype = dtype
@@ -244,12 +378,18 @@ class CountVectorizer(BaseEstimator):
 
     def _validate_params(self):
 
-        if self.analyzer != "word":
-            raise NotImplementedError
+        if self.analyzer not in ("word", "char", "char_wb"):
+            raise ValueError(
+                "analyzer={} must be one of 'word', 'char', 'char_wb'".format(
+                    self.analyzer
+                )
+            )
 
         if not isinstance(self.n_jobs, int) or self.n_jobs < 1:
             raise ValueError("n_jobs={} must be a integer >= 1".format(self.n_jobs))
 
+        _validate_dtype(self.dtype)
+
     def fit(self, raw_documents, y=None):
         """Learn a vocabulary dictionary of all tokens in the raw documents.
 
@@ -263,7 +403,12 @@ class CountVectorizer(BaseEstimator):
         self
         """
         self._validate_params()
-        self._vect = _lib._CountVectorizerWrapper(self.n_jobs)
+        self._vect = _lib._CountVectorizerWrapper(
+            n_jobs=self.n_jobs,
+            analyzer=self.analyzer,
+            ngram_range=tuple(self.ngram_range),
+            binary=self.binary,
+        )
         self._vect.fit(raw_documents)
         return self
 
@@ -294,14 +439,18 @@ class CountVectorizer(BaseEstimator):
         self._validate_params()
         self._validate_vocabulary()
 
-        self._vect = _lib._CountVectorizerWrapper(n_jobs=self.n_jobs)
-        indices, indptr, data = self._vect.fit_transform(raw_documents)
+        self._vect = _lib._CountVectorizerWrapper(
+            n_jobs=self.n_jobs,
+            analyzer=self.analyzer,
+            ngram_range=tuple(self.ngram_range),
+            binary=self.binary,
+        )
+        indices, indptr, data = self._vect.fit_transform(
+            raw_documents, dtype=np.dtype(self.dtype).name
+        )
         n_features = self._vect.get_n_features()
         X = sp.csr_matrix((data, indices, indptr), shape=(len(indptr) - 1, n_features))
 
-        if self.binary:
-            X.data.fill(1)
-
         return X
 
     def transform(self, raw_documents):
@@ -333,19 +482,17 @@ class CountVectorizer(BaseEstimator):
         self._check_vocabulary()
 
         # use the same matrix-building strategy as fit_transform
-        indices, indptr, data = self._vect.transform(raw_documents)
+        indices, indptr, data = self._vect.transform(
+            raw_documents, dtype=np.dtype(self.dtype).name
+        )
         n_features = self._vect.get_n_features()
         X = sp.csr_matrix((data, indices, indptr), shape=(len(indptr) - 1, n_features))
 
-        if self.binary:
-            X.data.fill(1)
         return X
 
     def inverse_transform(self, X):
         """Return terms per document with nonzero entries in X.
 
-        .. note:: Not implemented
-
         Parameters
         ----------
         X : {array, sparse matrix}, shape = [n_samples, n_features]
@@ -355,11 +502,25 @@ class CountVectorizer(BaseEstimator):
         X_inv : list of arrays, len = n_samples
             List of arrays of terms.
         """
-        raise NotImplementedError()
+        if not hasattr(self, "_vect"):
+            raise ValueError("Model need to be fitted first!")
+        X = sp.csr_matrix(X)
+        X_inv = self._vect.inverse_transform(
+            X.shape,
+            X.indptr.astype(np.int64),
+            X.indices.astype(np.int64),
+            X.data.astype(np.float64),
+        )
+        return [np.array(terms, dtype=str) for terms in X_inv]
 
     def get_feature_names(self):
         """Array mapping from feature integer indices to feature name
 
-        .. note:: Not implemented
+        Returns
+        -------
+        feature_names : list
+            A list of feature names.
         """
-        raise NotImplementedError()
+        if not hasattr(self, "_vect"):
+            raise ValueError("Model need to be fitted first!")
+        return self._vect.get_feature_names()
@@ -7,11 +7,36 @@ use thiserror::Error;
 pub enum EstimatorErr {
     #[error("Invalid paramer: `{0}`")]
     InvalidParams(String),
+    #[error("Estimator `{0}` must be fitted first")]
+    NotFitted(String),
     #[error("Invalid regex parameter")]
     RegexErr {
         #[from]
         source: regex::Error,
     },
+    #[error("IO error")]
+    IoErr {
+        #[from]
+        source: std::io::Error,
+    },
+    #[error("Binary serialization error: {source}")]
+    BinaryErr {
+        #[from]
+        source: bincode::Error,
+    },
+    #[error("JSON serialization error: {source}")]
+    JsonErr {
+        #[from]
+        source: serde_json::Error,
+    },
+    #[error("Invalid serialized estimator: `{0}`")]
+    InvalidFormat(String),
+    #[cfg(feature = "rayon")]
+    #[error("Thread pool error: {source}")]
+    ThreadPoolErr {
+        #[from]
+        source: rayon::ThreadPoolBuildError,
+    },
 }
 
 #[cfg(feature = "python")]
@@ -18,7 +18,11 @@ machine learning applications.
  - Token counting: converting token counts to sparse matrices for use
    in machine learning libraries. Similar to `CountVectorizer` and
    `HashingVectorizer` in scikit-learn but will less broad functionality.
+ - TF-IDF weighting of token counts, similar to `TfidfTransformer` and `TfidfVectorizer` in
+   scikit-learn.
+ - Stemming: Snowball stemmer
  - Levenshtein edit distance; Sørensen-Dice, Jaro, Jaro Winkler string similarities
+ - Persistence of fitted estimators in a binary or JSON format
 
 # Example
 
@@ -41,6 +45,9 @@ assert_eq!(tokens, vec!["Flights", "ca", "n't", "depart", "after", "2:00", "pm",
 pub mod errors;
 mod math;
 pub mod metrics;
+#[macro_use]
+pub mod persistence;
+pub mod stem;
 pub mod tokenize;
 pub mod tokenize_sentence;
 pub mod vectorize;
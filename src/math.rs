@@ -5,8 +5,8 @@
 // modified, or distributed except according to those terms.
 
 #[derive(Debug)]
-pub struct CSRArray {
+pub struct CSRArray<N = i32> {
     pub indices: Vec<usize>,
     pub indptr: Vec<usize>,
-    pub data: Vec<i32>,
+    pub data: Vec<N>,
 }
@@ -0,0 +1,147 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+/*!
+# Persistence module
+
+This module allows saving fitted estimators to disk and loading them back,
+
+```rust
+use vtext::persistence::Format;
+use vtext::tokenize::RegexpTokenizer;
+use vtext::vectorize::CountVectorizer;
+
+let documents = vec![String::from("Some text input"), String::from("Another line")];
+let mut vectorizer = CountVectorizer::<RegexpTokenizer>::default();
+vectorizer.fit(&documents);
+
+let path = std::env::temp_dir().join("vtext_count_vectorizer.json");
+vectorizer.save(&path, Format::Json).unwrap();
+
+let mut vectorizer2 = CountVectorizer::<RegexpTokenizer>::load(&path, Format::Json).unwrap();
+assert_eq!(vectorizer2.vocabulary, vectorizer.vocabulary);
+# std::fs::remove_file(&path).unwrap();
+```
+
+Two formats are supported,
+ - `Format::Binary`: a compact [bincode](https://github.com/servo/bincode) encoding, preceded by
+   a header with the format version.
+ - `Format::Json`: a human readable JSON object, with the format version in the
+   `format_version` field and the estimator in the `estimator` field.
+
+Built tokenizers are stored by their parameters, and are built again when loaded.
+*/
+
+use crate::errors::EstimatorErr;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{Read, Write};
+
+/// Version of the serialization format, incremented on incompatible changes
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Magic bytes at the start of the binary format
+const MAGIC: &[u8; 4] = b"VTXT";
+
+/// Serialization format
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Binary,
+    Json,
+}
+
+/// Implement serde for a built estimator, by serializing its parameters
+///
+/// The estimator must have a public `params` field, and the parameters a `build` method.
+macro_rules! impl_params_serde {
+    ($estimator:ty, $params:ty) => {
+        impl std::convert::TryFrom<$params> for $estimator {
+            type Error = crate::errors::EstimatorErr;
+
+            fn try_from(mut params: $params) -> Result<$estimator, Self::Error> {
+                params.build()
+            }
+        }
+
+        impl From<$estimator> for $params {
+            fn from(estimator: $estimator) -> $params {
+                estimator.params
+            }
+        }
+    };
+}
+
+/// Serialize an estimator to a writer
+pub fn save<E, W>(estimator: &E, mut writer: W, format: Format) -> Result<(), EstimatorErr>
+where
+    E: Serialize,
+    W: Write,
+{
+    match format {
+        Format::Binary => {
+            writer.write_all(MAGIC)?;
+            writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+            bincode::serialize_into(&mut writer, estimator)?;
+        }
+        Format::Json => {
+            let document = serde_json::json!({
+                "format_version": FORMAT_VERSION,
+                "estimator": estimator,
+            });
+            serde_json::to_writer(&mut writer, &document)?;
+        }
+    };
+    writer.flush()?;
+    Ok(())
+}
+
+/// Deserialize an estimator from a reader
+pub fn load<E, R>(mut reader: R, format: Format) -> Result<E, EstimatorErr>
+where
+    E: DeserializeOwned,
+    R: Read,
+{
+    match format {
+        Format::Binary => {
+            let mut header = [0u8; 8];
+            reader.read_exact(&mut header)?;
+            if &header[..4] != MAGIC {
+                return Err(EstimatorErr::InvalidFormat(
+                    "not a vtext binary file".to_string(),
+                ));
+            }
+            let mut version = [0u8; 4];
+            version.copy_from_slice(&header[4..]);
+            check_version(u32::from_le_bytes(version))?;
+            Ok(bincode::deserialize_from(reader)?)
+        }
+        Format::Json => {
+            let mut document: serde_json::Value = serde_json::from_reader(reader)?;
+            match document.get("format_version").and_then(|x| x.as_u64()) {
+                Some(version) => check_version(version as u32)?,
+                None => {
+                    return Err(EstimatorErr::InvalidFormat(
+                        "missing format_version".to_string(),
+                    ))
+                }
+            };
+            match document.get_mut("estimator") {
+                Some(estimator) => Ok(serde_json::from_value(estimator.take())?),
+                None => Err(EstimatorErr::InvalidFormat("missing estimator".to_string())),
+            }
+        }
+    }
+}
+
+fn check_version(version: u32) -> Result<(), EstimatorErr> {
+    if version != FORMAT_VERSION {
+        return Err(EstimatorErr::InvalidFormat(format!(
+            "format_version={} is not supported, expected {}",
+            version, FORMAT_VERSION
+        )));
+    }
+    Ok(())
+}
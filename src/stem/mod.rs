@@ -0,0 +1,131 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+/*!
+# Stemming module
+
+This module includes stemmers that reduce words to their root form.
+
+```rust
+use vtext::stem::*;
+
+let stemmer = SnowballStemmerParams::default().lang("english").build().unwrap();
+assert_eq!(stemmer.stem("fishing"), "fish");
+```
+*/
+
+use crate::errors::EstimatorErr;
+#[cfg(feature = "python")]
+use dict_derive::{FromPyObject, IntoPyObject};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+pub trait Stemmer: fmt::Debug {
+    fn stem<'a>(&self, word: &'a str) -> Cow<'a, str>;
+}
+
+/// Snowball stemmer
+///
+/// Wraps the rust-stemmers crate that uses an implementation generated
+/// by the [Snowball compiler](https://github.com/snowballstem/snowball)
+/// for Rust.
+///
+/// Supported languages are: arabic, danish, dutch, english, french, german, greek,
+/// hungarian, italian, portuguese, romanian, russian, spanish, swedish, tamil, turkish.
+#[derive(Serialize, Deserialize)]
+#[serde(try_from = "SnowballStemmerParams", into = "SnowballStemmerParams")]
+pub struct SnowballStemmer {
+    pub params: SnowballStemmerParams,
+    inner: rust_stemmers::Stemmer,
+}
+
+/// Builder for the Snowball stemmer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", derive(FromPyObject, IntoPyObject))]
+pub struct SnowballStemmerParams {
+    lang: String,
+}
+
+fn get_algorithm(lang: &str) -> Result<rust_stemmers::Algorithm, EstimatorErr> {
+    match lang {
+        "arabic" => Ok(rust_stemmers::Algorithm::Arabic),
+        "danish" => Ok(rust_stemmers::Algorithm::Danish),
+        "dutch" => Ok(rust_stemmers::Algorithm::Dutch),
+        "english" => Ok(rust_stemmers::Algorithm::English),
+        "french" => Ok(rust_stemmers::Algorithm::French),
+        "german" => Ok(rust_stemmers::Algorithm::German),
+        "greek" => Ok(rust_stemmers::Algorithm::Greek),
+        "hungarian" => Ok(rust_stemmers::Algorithm::Hungarian),
+        "italian" => Ok(rust_stemmers::Algorithm::Italian),
+        "portuguese" => Ok(rust_stemmers::Algorithm::Portuguese),
+        "romanian" => Ok(rust_stemmers::Algorithm::Romanian),
+        "russian" => Ok(rust_stemmers::Algorithm::Russian),
+        "spanish" => Ok(rust_stemmers::Algorithm::Spanish),
+        "swedish" => Ok(rust_stemmers::Algorithm::Swedish),
+        "tamil" => Ok(rust_stemmers::Algorithm::Tamil),
+        "turkish" => Ok(rust_stemmers::Algorithm::Turkish),
+        _ => Err(EstimatorErr::InvalidParams(format!(
+            "lang={} is unsupported!",
+            lang
+        ))),
+    }
+}
+
+impl SnowballStemmerParams {
+    pub fn lang(&mut self, value: &str) -> SnowballStemmerParams {
+        self.lang = value.to_string();
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<SnowballStemmer, EstimatorErr> {
+        let algorithm = get_algorithm(&self.lang)?;
+        Ok(SnowballStemmer {
+            params: self.clone(),
+            inner: rust_stemmers::Stemmer::create(algorithm),
+        })
+    }
+}
+
+impl Default for SnowballStemmerParams {
+    /// Create a new instance
+    fn default() -> SnowballStemmerParams {
+        SnowballStemmerParams {
+            lang: "english".to_string(),
+        }
+    }
+}
+
+impl Default for SnowballStemmer {
+    /// Create a new instance
+    fn default() -> SnowballStemmer {
+        SnowballStemmerParams::default().build().unwrap()
+    }
+}
+
+impl Clone for SnowballStemmer {
+    fn clone(&self) -> SnowballStemmer {
+        // parameters were already validated when building self
+        self.params.clone().build().unwrap()
+    }
+}
+
+impl fmt::Debug for SnowballStemmer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SnowballStemmer {{ lang: {} }}", self.params.lang)
+    }
+}
+
+impl Stemmer for SnowballStemmer {
+    /// Stem a word
+    fn stem<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        self.inner.stem(word)
+    }
+}
+
+impl_params_serde!(SnowballStemmer, SnowballStemmerParams);
@@ -0,0 +1,31 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::stem::*;
+
+#[test]
+fn test_snowball_stemmer() {
+    let stemmer = SnowballStemmer::default();
+    assert_eq!(stemmer.stem("fishing"), "fish");
+    assert_eq!(stemmer.stem("fished"), "fish");
+    assert_eq!(stemmer.stem("fox"), "fox");
+
+    let stemmer = SnowballStemmerParams::default()
+        .lang("french")
+        .build()
+        .unwrap();
+    assert_eq!(stemmer.stem("continuellement"), "continuel");
+
+    let stemmer = stemmer.clone();
+    assert_eq!(stemmer.params.lang, "french");
+    assert_eq!(stemmer.stem("continuellement"), "continuel");
+}
+
+#[test]
+fn test_snowball_stemmer_invalid_lang() {
+    let stemmer = SnowballStemmerParams::default().lang("klingon").build();
+    assert!(stemmer.is_err());
+}
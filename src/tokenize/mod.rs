@@ -55,19 +55,133 @@ use crate::errors::EstimatorErr;
 use dict_derive::{FromPyObject, IntoPyObject};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cmp;
 use std::fmt;
 use unicode_segmentation::UnicodeSegmentation;
 
+mod case_folding;
+mod pipeline;
+mod stop_words;
 #[cfg(test)]
 mod tests;
 
+pub use case_folding::CaseFolding;
+pub use pipeline::{TokenPipeline, TokenPipelineParams, TokenTransform};
+pub use stop_words::{
+    get_stop_words, load_stop_words, StopWordFilter, StopWordFilterParams, StopWordTokenizer,
+};
+
+/// Token with its position in the input text
+///
+/// `start` and `end` delimit the token in the input text, either as byte offsets
+/// (`Tokenizer::tokenize_with_offsets`) or as character offsets
+/// (`Tokenizer::tokenize_with_char_offsets`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Tokenization of a string into slices of this string
+///
+/// Tokens must be slices of the input text, their offsets are computed from their
+/// position in memory.
 pub trait Tokenizer: fmt::Debug {
     fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a>;
+
+    /// Tokenize a string, returning tokens with their byte offsets
+    ///
+    /// ```rust
+    /// use vtext::tokenize::*;
+    ///
+    /// let tokenizer = UnicodeWordTokenizer::default();
+    /// let tokens: Vec<Token> = tokenizer.tokenize_with_offsets("Où va-t-il").collect();
+    /// assert_eq!(tokens[1], Token { text: "va", start: 4, end: 6 });
+    /// assert_eq!(&"Où va-t-il"[4..6], "va");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a token is not a slice of `text`.
+    fn tokenize_with_offsets<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        let base = text.as_ptr() as usize;
+        Box::new(self.tokenize(text).map(move |token| {
+            let ptr = token.as_ptr() as usize;
+            assert!(
+                ptr >= base && ptr + token.len() <= base + text.len(),
+                "token {:?} is not a slice of the input text, its offsets cannot be computed",
+                token
+            );
+            let start = ptr - base;
+            Token {
+                text: token,
+                start,
+                end: start + token.len(),
+            }
+        }))
+    }
+
+    /// Tokenize a string, returning tokens with their character offsets
+    ///
+    /// Offsets are counted in Unicode scalar values, which is how Python indexes strings.
+    ///
+    /// ```rust
+    /// use vtext::tokenize::*;
+    ///
+    /// let tokenizer = UnicodeWordTokenizer::default();
+    /// let tokens: Vec<Token> = tokenizer.tokenize_with_char_offsets("Où va-t-il").collect();
+    /// assert_eq!(tokens[1], Token { text: "va", start: 3, end: 5 });
+    /// ```
+    fn tokenize_with_char_offsets<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Box<dyn Iterator<Item = Token<'a>> + 'a> {
+        // Last byte offset converted to characters, tokens are usually sorted
+        // by position, so characters only need to be counted once.
+        let mut byte_pos = 0;
+        let mut char_pos = 0;
+        Box::new(self.tokenize_with_offsets(text).map(move |token| {
+            if token.start < byte_pos {
+                byte_pos = 0;
+                char_pos = 0;
+            }
+            char_pos += text[byte_pos..token.start].chars().count();
+            byte_pos = token.start;
+            Token {
+                text: token.text,
+                start: char_pos,
+                end: char_pos + text[token.start..token.end].chars().count(),
+            }
+        }))
+    }
+}
+
+/// Tokenization with possibly normalized tokens
+///
+/// Unlike `Tokenizer`, tokens are not necessarily slices of the input text,
+/// which allows applying transforms such as lowercasing or stemming. This is
+/// what vectorizers use to extract features.
+///
+/// All tokenizers implement this trait.
+pub trait Analyzer: fmt::Debug {
+    fn analyze<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a>;
+}
+
+impl<T: Tokenizer> Analyzer for T {
+    fn analyze<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a> {
+        Box::new(self.tokenize(text).map(Cow::Borrowed))
+    }
 }
 
 /// Regular expression tokenizer
 ///
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "RegexpTokenizerParams", into = "RegexpTokenizerParams")]
 pub struct RegexpTokenizer {
     pub params: RegexpTokenizerParams,
     regexp: Regex,
@@ -132,7 +246,11 @@ impl fmt::Debug for RegexpTokenizer {
 /// ## References
 ///
 /// * [Unicode® Standard Annex #29](http://www.unicode.org/reports/tr29/)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "UnicodeWordTokenizerParams",
+    into = "UnicodeWordTokenizerParams"
+)]
 pub struct UnicodeWordTokenizer {
     pub params: UnicodeWordTokenizerParams,
 }
@@ -194,7 +312,8 @@ impl Tokenizer for UnicodeWordTokenizer {
 /// ## References
 ///
 /// * [Unicode® Standard Annex #29](http://www.unicode.org/reports/tr29/)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "VTextTokenizerParams", into = "VTextTokenizerParams")]
 pub struct VTextTokenizer {
     pub params: VTextTokenizerParams,
 }
@@ -356,7 +475,23 @@ impl Tokenizer for VTextTokenizer {
 }
 
 /// Character tokenizer
-#[derive(Debug, Clone)]
+///
+/// Splits text into overlapping character n-grams, with n in the `ngram_range`.
+/// N-grams are ordered by start position, then by length. A non empty text that is
+/// shorter than the smallest n-gram is returned as a single token.
+///
+/// ```rust
+/// use vtext::tokenize::*;
+///
+/// let tokenizer = CharacterTokenizerParams::default().ngram_range(2, 3).build().unwrap();
+/// let tokens: Vec<&str> = tokenizer.tokenize("fox").collect();
+/// assert_eq!(tokens, &["fo", "fox", "ox"]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "CharacterTokenizerParams",
+    into = "CharacterTokenizerParams"
+)]
 pub struct CharacterTokenizer {
     pub params: CharacterTokenizerParams,
 }
@@ -365,14 +500,44 @@ pub struct CharacterTokenizer {
 #[cfg_attr(feature = "python", derive(FromPyObject, IntoPyObject))]
 pub struct CharacterTokenizerParams {
     window_size: usize,
+    #[serde(default)]
+    ngram_range: Option<(usize, usize)>,
+}
+
+/// Check that a character n-gram range is valid
+fn _check_char_ngram_range(ngram_range: (usize, usize)) -> Result<(), EstimatorErr> {
+    let (min_n, max_n) = ngram_range;
+    if min_n < 1 || min_n > max_n {
+        return Err(EstimatorErr::InvalidParams(format!(
+            "ngram_range=({}, {}) must satisfy 1 <= min_n <= max_n",
+            min_n, max_n
+        )));
+    }
+    Ok(())
 }
 
 impl CharacterTokenizerParams {
+    /// Number of consecutive characters in a token
+    ///
+    /// This is equivalent to `ngram_range(value, value)`, and unsets `ngram_range`.
     pub fn window_size(&mut self, value: usize) -> CharacterTokenizerParams {
         self.window_size = value;
+        self.ngram_range = None;
         self.clone()
     }
+    /// The lower and upper boundary of the range of character n-grams lengths
+    ///
+    /// If set, `window_size` is ignored.
+    pub fn ngram_range(&mut self, min_n: usize, max_n: usize) -> CharacterTokenizerParams {
+        self.ngram_range = Some((min_n, max_n));
+        self.clone()
+    }
+    fn _ngram_range(&self) -> (usize, usize) {
+        self.ngram_range
+            .unwrap_or((self.window_size, self.window_size))
+    }
     pub fn build(&mut self) -> Result<CharacterTokenizer, EstimatorErr> {
+        _check_char_ngram_range(self._ngram_range())?;
         Ok(CharacterTokenizer {
             params: self.clone(),
         })
@@ -381,7 +546,10 @@ impl CharacterTokenizerParams {
 
 impl Default for CharacterTokenizerParams {
     fn default() -> CharacterTokenizerParams {
-        CharacterTokenizerParams { window_size: 4 }
+        CharacterTokenizerParams {
+            window_size: 4,
+            ngram_range: None,
+        }
     }
 }
 
@@ -395,14 +563,159 @@ impl Default for CharacterTokenizer {
 impl Tokenizer for CharacterTokenizer {
     /// Tokenize a string
     fn tokenize<'a>(&self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
-        let res = text
+        let (min_n, max_n) = self.params._ngram_range();
+        if min_n == max_n {
+            // fast path for a fixed window size
+            let res = text
+                .char_indices()
+                .zip(
+                    text.char_indices()
+                        .skip(min_n)
+                        .chain(Some((text.len(), ' '))),
+                )
+                .map(move |((i, _), (j, _))| &text[i..j]);
+            return Box::new(res);
+        }
+
+        // byte offsets of character boundaries, including the end of the text
+        let bounds: Vec<usize> = text
             .char_indices()
-            .zip(
-                text.char_indices()
-                    .skip(self.params.window_size)
-                    .chain(Some((text.len(), ' '))),
-            )
-            .map(move |((i, _), (j, _))| &text[i..j]);
-        Box::new(res)
+            .map(|(idx, _)| idx)
+            .chain(Some(text.len()))
+            .collect();
+        let n_chars = bounds.len() - 1;
+        if n_chars > 0 && n_chars < min_n {
+            return Box::new(Some(text).into_iter());
+        }
+
+        let mut res: Vec<&'a str> = Vec::new();
+        for start in 0..n_chars {
+            for n in min_n..=max_n {
+                if start + n > n_chars {
+                    break;
+                }
+                res.push(&text[bounds[start]..bounds[start + n]]);
+            }
+        }
+        Box::new(res.into_iter())
     }
 }
+
+/// Character n-grams analyzer within word boundaries
+///
+/// Extracts character n-grams from each whitespace separated word, padded with a
+/// space on each side, so that n-grams at the edges of words are distinguished from
+/// n-grams inside words. This is equivalent to `analyzer="char_wb"` in scikit-learn.
+///
+/// Since padded n-grams are not slices of the input text, this implements `Analyzer`
+/// and not `Tokenizer`. It can be used as the tokenizer of vectorizers.
+///
+/// ```rust
+/// use vtext::tokenize::*;
+///
+/// let analyzer = CharacterWbAnalyzerParams::default().ngram_range(3, 3).build().unwrap();
+/// let tokens: Vec<String> = analyzer.analyze("a fox").map(|x| x.to_string()).collect();
+/// assert_eq!(tokens, &[" a ", " fo", "fox", "ox "]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "CharacterWbAnalyzerParams",
+    into = "CharacterWbAnalyzerParams"
+)]
+pub struct CharacterWbAnalyzer {
+    pub params: CharacterWbAnalyzerParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", derive(FromPyObject, IntoPyObject))]
+pub struct CharacterWbAnalyzerParams {
+    ngram_range: (usize, usize),
+}
+
+impl CharacterWbAnalyzerParams {
+    /// The lower and upper boundary of the range of character n-grams lengths
+    pub fn ngram_range(&mut self, min_n: usize, max_n: usize) -> CharacterWbAnalyzerParams {
+        self.ngram_range = (min_n, max_n);
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<CharacterWbAnalyzer, EstimatorErr> {
+        _check_char_ngram_range(self.ngram_range)?;
+        Ok(CharacterWbAnalyzer {
+            params: self.clone(),
+        })
+    }
+}
+
+impl Default for CharacterWbAnalyzerParams {
+    fn default() -> CharacterWbAnalyzerParams {
+        CharacterWbAnalyzerParams {
+            ngram_range: (4, 4),
+        }
+    }
+}
+
+impl Default for CharacterWbAnalyzer {
+    /// Create a new instance
+    fn default() -> CharacterWbAnalyzer {
+        CharacterWbAnalyzerParams::default().build().unwrap()
+    }
+}
+
+impl Analyzer for CharacterWbAnalyzer {
+    /// Extract padded character n-grams of each word
+    fn analyze<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a> {
+        let (min_n, max_n) = self.params.ngram_range;
+        let mut res: Vec<Cow<'a, str>> = Vec::new();
+
+        for word in text.split_whitespace() {
+            let bounds: Vec<usize> = word
+                .char_indices()
+                .map(|(idx, _)| idx)
+                .chain(Some(word.len()))
+                .collect();
+            // number of characters in the padded word
+            let n_padded = bounds.len() + 1;
+
+            // Slice of the padded word between characters `start` and `end`.
+            // N-grams that don't include padding are borrowed from the text.
+            let ngram = |start: usize, end: usize| -> Cow<'a, str> {
+                let inner =
+                    &word[bounds[cmp::max(start, 1) - 1]..bounds[cmp::min(end, n_padded - 1) - 1]];
+                match (start == 0, end == n_padded) {
+                    (false, false) => Cow::Borrowed(inner),
+                    (left, right) => {
+                        let mut token = String::with_capacity(inner.len() + 2);
+                        if left {
+                            token.push(' ');
+                        }
+                        token.push_str(inner);
+                        if right {
+                            token.push(' ');
+                        }
+                        Cow::Owned(token)
+                    }
+                }
+            };
+
+            for n in min_n..=max_n {
+                res.push(ngram(0, cmp::min(n, n_padded)));
+                let mut offset = 0;
+                while offset + n < n_padded {
+                    offset += 1;
+                    res.push(ngram(offset, offset + n));
+                }
+                if offset == 0 {
+                    // words shorter than n are only counted once
+                    break;
+                }
+            }
+        }
+        Box::new(res.into_iter())
+    }
+}
+
+impl_params_serde!(RegexpTokenizer, RegexpTokenizerParams);
+impl_params_serde!(UnicodeWordTokenizer, UnicodeWordTokenizerParams);
+impl_params_serde!(VTextTokenizer, VTextTokenizerParams);
+impl_params_serde!(CharacterTokenizer, CharacterTokenizerParams);
+impl_params_serde!(CharacterWbAnalyzer, CharacterWbAnalyzerParams);
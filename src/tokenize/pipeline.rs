@@ -0,0 +1,230 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::errors::EstimatorErr;
+use crate::stem::{SnowballStemmer, SnowballStemmerParams, Stemmer};
+use crate::tokenize::{Analyzer, CaseFolding, StopWordFilter, StopWordFilterParams, Tokenizer};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Transform applied to each token in a `TokenPipeline`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TokenTransform {
+    /// Apply case folding to tokens
+    CaseFolding(CaseFolding),
+    /// Stem tokens with a Snowball stemmer
+    Stem(SnowballStemmerParams),
+    /// Remove stop words
+    StopWords(StopWordFilterParams),
+    /// Remove tokens with fewer characters
+    MinLength(usize),
+    /// Remove tokens with more characters
+    MaxLength(usize),
+    /// Only keep tokens matching this regular expression
+    RegexFilter(String),
+}
+
+/// Built token transform
+#[derive(Debug, Clone)]
+enum TokenStep {
+    CaseFolding(CaseFolding),
+    Stem(SnowballStemmer),
+    StopWords(StopWordFilter),
+    MinLength(usize),
+    MaxLength(usize),
+    RegexFilter(Regex),
+}
+
+impl TokenTransform {
+    fn build(&self) -> Result<TokenStep, EstimatorErr> {
+        Ok(match self {
+            TokenTransform::CaseFolding(value) => TokenStep::CaseFolding(*value),
+            TokenTransform::Stem(params) => TokenStep::Stem(params.clone().build()?),
+            TokenTransform::StopWords(params) => TokenStep::StopWords(params.clone().build()?),
+            TokenTransform::MinLength(value) => TokenStep::MinLength(*value),
+            TokenTransform::MaxLength(value) => TokenStep::MaxLength(*value),
+            TokenTransform::RegexFilter(pattern) => TokenStep::RegexFilter(Regex::new(pattern)?),
+        })
+    }
+}
+
+/// Apply a string transform to a token, without copying it when unchanged
+#[inline]
+fn map_token<'a, F>(token: Cow<'a, str>, f: F) -> Cow<'a, str>
+where
+    F: for<'b> Fn(&'b str) -> Cow<'b, str>,
+{
+    match token {
+        Cow::Borrowed(token) => f(token),
+        Cow::Owned(token) => {
+            let transformed = match f(&token) {
+                Cow::Borrowed(_) => None,
+                Cow::Owned(transformed) => Some(transformed),
+            };
+            Cow::Owned(transformed.unwrap_or(token))
+        }
+    }
+}
+
+/// Token pipeline
+///
+/// Applies an ordered list of transforms (case folding, stemming, stop word removal,
+/// token length and regular expression filters) on the output of a tokenizer.
+///
+/// Since tokens may be modified, the pipeline implements `Analyzer` rather than `Tokenizer`,
+/// and can be used as the tokenizer of vectorizers.
+///
+/// ```rust
+/// use vtext::tokenize::*;
+/// use vtext::stem::SnowballStemmerParams;
+///
+/// let pipeline = TokenPipelineParams::default()
+///     .tokenizer(VTextTokenizer::default())
+///     .lowercase()
+///     .stop_words(StopWordFilterParams::default().lang("en"))
+///     .stem(SnowballStemmerParams::default().lang("english"))
+///     .min_length(2)
+///     .build()
+///     .unwrap();
+/// let tokens: Vec<String> = pipeline
+///     .analyze("The Fishes are swimming.")
+///     .map(|token| token.to_string())
+///     .collect();
+/// assert_eq!(tokens, &["fish", "swim"]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "TokenPipelineParams<T>",
+    into = "TokenPipelineParams<T>",
+    bound(
+        serialize = "T: Serialize + Tokenizer + Clone",
+        deserialize = "T: Deserialize<'de> + Tokenizer + Clone"
+    )
+)]
+pub struct TokenPipeline<T> {
+    pub params: TokenPipelineParams<T>,
+    steps: Vec<TokenStep>,
+}
+
+/// Builder for the token pipeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenPipelineParams<T> {
+    tokenizer: T,
+    transforms: Vec<TokenTransform>,
+}
+
+impl<T: Tokenizer + Clone> TokenPipelineParams<T> {
+    pub fn tokenizer(&mut self, value: T) -> TokenPipelineParams<T> {
+        self.tokenizer = value;
+        self.clone()
+    }
+    /// Append a transform to the pipeline
+    pub fn transform(&mut self, value: TokenTransform) -> TokenPipelineParams<T> {
+        self.transforms.push(value);
+        self.clone()
+    }
+    /// Append a Unicode lowercase transform
+    pub fn lowercase(&mut self) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::CaseFolding(CaseFolding::Lowercase))
+    }
+    /// Append a case folding transform
+    pub fn case_folding(&mut self, value: CaseFolding) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::CaseFolding(value))
+    }
+    /// Append a Snowball stemmer
+    pub fn stem(&mut self, value: SnowballStemmerParams) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::Stem(value))
+    }
+    /// Append a stop word filter
+    pub fn stop_words(&mut self, value: StopWordFilterParams) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::StopWords(value))
+    }
+    /// Append a filter removing tokens with less than `value` characters
+    pub fn min_length(&mut self, value: usize) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::MinLength(value))
+    }
+    /// Append a filter removing tokens with more than `value` characters
+    pub fn max_length(&mut self, value: usize) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::MaxLength(value))
+    }
+    /// Append a filter keeping only tokens that match a regular expression
+    pub fn regex_filter(&mut self, pattern: &str) -> TokenPipelineParams<T> {
+        self.transform(TokenTransform::RegexFilter(pattern.to_string()))
+    }
+    pub fn build(&mut self) -> Result<TokenPipeline<T>, EstimatorErr> {
+        let steps = self
+            .transforms
+            .iter()
+            .map(|transform| transform.build())
+            .collect::<Result<Vec<TokenStep>, EstimatorErr>>()?;
+        Ok(TokenPipeline {
+            params: self.clone(),
+            steps,
+        })
+    }
+}
+
+impl<T: Tokenizer + Clone + Default> Default for TokenPipelineParams<T> {
+    /// Create a new instance
+    fn default() -> TokenPipelineParams<T> {
+        TokenPipelineParams {
+            tokenizer: T::default(),
+            transforms: Vec::new(),
+        }
+    }
+}
+
+impl<T: Tokenizer + Clone + Default> Default for TokenPipeline<T> {
+    /// Create a new instance
+    fn default() -> TokenPipeline<T> {
+        TokenPipelineParams::default().build().unwrap()
+    }
+}
+
+impl<T: Tokenizer + Clone> std::convert::TryFrom<TokenPipelineParams<T>> for TokenPipeline<T> {
+    type Error = EstimatorErr;
+
+    fn try_from(mut params: TokenPipelineParams<T>) -> Result<TokenPipeline<T>, EstimatorErr> {
+        params.build()
+    }
+}
+
+impl<T> From<TokenPipeline<T>> for TokenPipelineParams<T> {
+    fn from(pipeline: TokenPipeline<T>) -> TokenPipelineParams<T> {
+        pipeline.params
+    }
+}
+
+impl<T: Tokenizer> Analyzer for TokenPipeline<T> {
+    /// Tokenize a string and apply transforms
+    fn analyze<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a> {
+        let mut tokens: Box<dyn Iterator<Item = Cow<'a, str>> + 'a> =
+            Box::new(self.params.tokenizer.tokenize(text).map(Cow::Borrowed));
+
+        for step in self.steps.iter() {
+            tokens = match step {
+                TokenStep::CaseFolding(case_folding) => {
+                    Box::new(tokens.map(move |token| map_token(token, |x| case_folding.apply(x))))
+                }
+                TokenStep::Stem(stemmer) => {
+                    Box::new(tokens.map(move |token| map_token(token, |x| stemmer.stem(x))))
+                }
+                TokenStep::StopWords(filter) => Box::new(filter.filter(tokens)),
+                TokenStep::MinLength(min_length) => {
+                    Box::new(tokens.filter(move |token| token.chars().count() >= *min_length))
+                }
+                TokenStep::MaxLength(max_length) => {
+                    Box::new(tokens.filter(move |token| token.chars().count() <= *max_length))
+                }
+                TokenStep::RegexFilter(regex) => {
+                    Box::new(tokens.filter(move |token| regex.is_match(token)))
+                }
+            };
+        }
+        tokens
+    }
+}
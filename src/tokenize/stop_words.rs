@@ -0,0 +1,185 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::errors::EstimatorErr;
+use crate::tokenize::Tokenizer;
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Built-in stop word lists, from the NLTK stopwords corpus (Tamil from stopwords-iso)
+static STOP_WORDS: &[(&str, &str, &str)] = &[
+    ("ar", "arabic", include_str!("stop_words/arabic.txt")),
+    ("da", "danish", include_str!("stop_words/danish.txt")),
+    ("nl", "dutch", include_str!("stop_words/dutch.txt")),
+    ("en", "english", include_str!("stop_words/english.txt")),
+    ("fr", "french", include_str!("stop_words/french.txt")),
+    ("de", "german", include_str!("stop_words/german.txt")),
+    ("el", "greek", include_str!("stop_words/greek.txt")),
+    ("hu", "hungarian", include_str!("stop_words/hungarian.txt")),
+    ("it", "italian", include_str!("stop_words/italian.txt")),
+    (
+        "pt",
+        "portuguese",
+        include_str!("stop_words/portuguese.txt"),
+    ),
+    ("ro", "romanian", include_str!("stop_words/romanian.txt")),
+    ("ru", "russian", include_str!("stop_words/russian.txt")),
+    ("es", "spanish", include_str!("stop_words/spanish.txt")),
+    ("sv", "swedish", include_str!("stop_words/swedish.txt")),
+    ("ta", "tamil", include_str!("stop_words/tamil.txt")),
+    ("tr", "turkish", include_str!("stop_words/turkish.txt")),
+];
+
+fn parse_stop_words(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Built-in stop word list for a language
+///
+/// `lang` can be either an ISO 639-1 code (e.g. "en") or the language name
+/// used by the Snowball stemmer (e.g. "english"). All stop words are lowercase.
+pub fn get_stop_words(lang: &str) -> Result<Vec<String>, EstimatorErr> {
+    match STOP_WORDS
+        .iter()
+        .find(|(code, name, _)| *code == lang || *name == lang)
+    {
+        Some((_, _, text)) => Ok(parse_stop_words(text)),
+        None => Err(EstimatorErr::InvalidParams(format!(
+            "stop words for lang={} are not available",
+            lang
+        ))),
+    }
+}
+
+/// Load a stop word list from a file
+///
+/// The file must contain one stop word per line. Empty lines and lines
+/// starting with `#` are ignored.
+pub fn load_stop_words<P: AsRef<Path>>(path: P) -> Result<Vec<String>, EstimatorErr> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_stop_words(&text))
+}
+
+/// Stop word filter
+///
+/// Removes tokens that belong to a list of stop words. It can be passed
+/// to vectorizers with the `stop_words` parameter, or wrap a tokenizer with
+/// `StopWordFilter::wrap`.
+///
+/// Matching is case sensitive, and the built-in lists are lowercase.
+///
+/// ```rust
+/// use vtext::tokenize::*;
+///
+/// let filter = StopWordFilterParams::default().lang("en").build().unwrap();
+/// let tokenizer = filter.wrap(RegexpTokenizer::default());
+/// let tokens: Vec<&str> = tokenizer.tokenize("the fox is in the forest").collect();
+/// assert_eq!(tokens, &["fox", "forest"]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "StopWordFilterParams", into = "StopWordFilterParams")]
+pub struct StopWordFilter {
+    pub params: StopWordFilterParams,
+    stop_words: HashSet<String>,
+}
+
+/// Builder for the stop word filter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StopWordFilterParams {
+    lang: Option<String>,
+    stop_words: Vec<String>,
+}
+
+impl StopWordFilterParams {
+    /// Use the built-in stop word list for this language
+    pub fn lang(&mut self, value: &str) -> StopWordFilterParams {
+        self.lang = Some(value.to_string());
+        self.clone()
+    }
+    /// Custom stop words, used in addition to the built-in list if `lang` is provided
+    pub fn stop_words(&mut self, value: Vec<String>) -> StopWordFilterParams {
+        self.stop_words = value;
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<StopWordFilter, EstimatorErr> {
+        let mut stop_words: HashSet<String> = self.stop_words.iter().cloned().collect();
+        if let Some(lang) = &self.lang {
+            stop_words.extend(get_stop_words(lang)?);
+        }
+        Ok(StopWordFilter {
+            params: self.clone(),
+            stop_words,
+        })
+    }
+}
+
+impl Default for StopWordFilterParams {
+    /// Create a new instance
+    fn default() -> StopWordFilterParams {
+        StopWordFilterParams {
+            lang: None,
+            stop_words: Vec::new(),
+        }
+    }
+}
+
+impl StopWordFilter {
+    /// Check if a token is a stop word
+    pub fn is_stop_word(&self, token: &str) -> bool {
+        self.stop_words.contains(token)
+    }
+
+    /// Remove stop words from a sequence of tokens
+    pub fn filter<'a, I, S>(&'a self, tokens: I) -> impl Iterator<Item = S> + 'a
+    where
+        I: Iterator<Item = S> + 'a,
+        S: AsRef<str>,
+    {
+        tokens.filter(move |token| !self.is_stop_word(token.as_ref()))
+    }
+
+    /// Wrap a tokenizer, removing stop words from its output
+    pub fn wrap<T: Tokenizer>(&self, tokenizer: T) -> StopWordTokenizer<T> {
+        StopWordTokenizer {
+            tokenizer,
+            filter: self.clone(),
+        }
+    }
+}
+
+/// Tokenizer with stop word removal
+///
+/// Created with `StopWordFilter::wrap`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StopWordTokenizer<T> {
+    pub tokenizer: T,
+    pub filter: StopWordFilter,
+}
+
+impl<T: Tokenizer> Tokenizer for StopWordTokenizer<T> {
+    /// Tokenize a string
+    fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        Box::new(self.filter.filter(self.tokenizer.tokenize(text)))
+    }
+}
+
+impl<T: Tokenizer + Default> Default for StopWordTokenizer<T> {
+    /// Create a new instance, with an empty stop word list
+    fn default() -> StopWordTokenizer<T> {
+        StopWordFilterParams::default()
+            .build()
+            .unwrap()
+            .wrap(T::default())
+    }
+}
+
+impl_params_serde!(StopWordFilter, StopWordFilterParams);
@@ -5,6 +5,7 @@
 // modified, or distributed except according to those terms.
 
 use crate::tokenize::*;
+use std::borrow::Cow;
 
 #[test]
 fn test_regexp_tokenizer() {
@@ -124,6 +125,58 @@ fn test_character_tokenizer() {
     let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
     let b: &[_] = &["fox ", "ox c", "x ca", " can", "can'", "an't"];
     assert_eq!(tokens, b);
+
+    let tokenizer = CharacterTokenizerParams::default()
+        .ngram_range(1, 3)
+        .build()
+        .unwrap();
+    let tokens: Vec<&str> = tokenizer.tokenize("été").collect();
+    assert_eq!(tokens, &["é", "ét", "été", "t", "té", "é"]);
+    // text shorter than the n-grams
+    let tokens: Vec<&str> = tokenizer.tokenize("").collect();
+    assert!(tokens.is_empty());
+    let tokenizer = CharacterTokenizerParams::default()
+        .ngram_range(3, 4)
+        .build()
+        .unwrap();
+    let tokens: Vec<&str> = tokenizer.tokenize("ab").collect();
+    assert_eq!(tokens, &["ab"]);
+
+    assert!(CharacterTokenizerParams::default()
+        .ngram_range(2, 1)
+        .build()
+        .is_err());
+    assert!(CharacterTokenizerParams::default()
+        .window_size(0)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_character_wb_analyzer() {
+    // reference values computed with scikit-learn's analyzer="char_wb"
+    let analyzer = CharacterWbAnalyzerParams::default()
+        .ngram_range(1, 2)
+        .build()
+        .unwrap();
+    let tokens: Vec<Cow<str>> = analyzer.analyze("ab  c\t").collect();
+    assert_eq!(
+        tokens,
+        &[" ", "a", "b", " ", " a", "ab", "b ", " ", "c", " ", " c", "c "]
+    );
+    // n-grams that are not padded are borrowed from the input
+    assert!(matches!(tokens[1], Cow::Borrowed("a")));
+    assert!(matches!(tokens[5], Cow::Borrowed("ab")));
+
+    let analyzer = CharacterWbAnalyzerParams::default()
+        .ngram_range(3, 5)
+        .build()
+        .unwrap();
+    let tokens: Vec<Cow<str>> = analyzer.analyze("a fox").collect();
+    assert_eq!(
+        tokens,
+        &[" a ", " fo", "fox", "ox ", " fox", "fox ", " fox "]
+    );
 }
 
 #[test]
@@ -131,3 +184,249 @@ fn test_tokenizer_defaults() {
     let tokenizer = UnicodeWordTokenizer::default();
     assert_eq!(tokenizer.params.word_bounds, true);
 }
+
+#[test]
+fn test_case_folding() {
+    let s = "ÉCOLE Straße ΣΊΣΥΦΟΣ";
+    assert_eq!(CaseFolding::None.apply(s), s);
+    assert_eq!(CaseFolding::Ascii.apply(s), "École straße ΣΊΣΥΦΟΣ");
+    assert_eq!(CaseFolding::Lowercase.apply(s), "école straße σίσυφος");
+    assert_eq!(CaseFolding::Casefold.apply(s), "école strasse σίσυφοσ");
+    assert_eq!(
+        CaseFolding::Casefold.apply("ﬁnance µ"),
+        CaseFolding::Casefold.apply("FINANCE Μ")
+    );
+
+    // no copy for unchanged text
+    assert!(matches!(
+        CaseFolding::Lowercase.apply("lowercase text"),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn test_stop_word_filter() {
+    let filter = StopWordFilterParams::default().lang("en").build().unwrap();
+    assert!(filter.is_stop_word("the"));
+    assert!(!filter.is_stop_word("fox"));
+
+    let tokenizer = filter.wrap(RegexpTokenizer::default());
+    let tokens: Vec<&str> = tokenizer.tokenize("the fox is in the forest").collect();
+    assert_eq!(tokens, &["fox", "forest"]);
+
+    // languages can be given by name or ISO 639-1 code
+    for lang in &["french", "fr", "german", "de", "russian", "swedish", "ta"] {
+        let words = get_stop_words(lang).unwrap();
+        assert!(!words.is_empty());
+    }
+    assert_eq!(
+        get_stop_words("fr").unwrap(),
+        get_stop_words("french").unwrap()
+    );
+    assert!(get_stop_words("klingon").is_err());
+    assert!(StopWordFilterParams::default().lang("xx").build().is_err());
+
+    // custom stop words are added to the built-in list
+    let filter = StopWordFilterParams::default()
+        .lang("en")
+        .stop_words(vec!["fox".to_string()])
+        .build()
+        .unwrap();
+    assert!(filter.is_stop_word("fox"));
+    assert!(filter.is_stop_word("the"));
+}
+
+#[test]
+fn test_load_stop_words() {
+    let path = std::env::temp_dir().join("vtext_test_load_stop_words.txt");
+    std::fs::write(&path, "# comment\nfox\n\n forest \n").unwrap();
+    let words = load_stop_words(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(words, &["fox", "forest"]);
+
+    assert!(load_stop_words("/non/existing/file.txt").is_err());
+}
+
+#[test]
+fn test_token_pipeline() {
+    let s = "The Fishes are swimming in the SEA, aren't they?";
+
+    // without transforms, the pipeline is equivalent to the tokenizer
+    let pipeline = TokenPipeline::<VTextTokenizer>::default();
+    let tokens: Vec<Cow<str>> = pipeline.analyze(s).collect();
+    let tokenizer = VTextTokenizer::default();
+    let tokens_ref: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(tokens, tokens_ref);
+
+    let pipeline = TokenPipelineParams::default()
+        .tokenizer(VTextTokenizer::default())
+        .lowercase()
+        .stop_words(StopWordFilterParams::default().lang("en"))
+        .stem(crate::stem::SnowballStemmerParams::default())
+        .regex_filter(r"^\w+$")
+        .build()
+        .unwrap();
+    let tokens: Vec<Cow<str>> = pipeline.analyze(s).collect();
+    assert_eq!(tokens, &["fish", "swim", "sea"]);
+
+    // transforms are applied in order
+    let pipeline = TokenPipelineParams::default()
+        .tokenizer(RegexpTokenizer::default())
+        .stop_words(StopWordFilterParams::default().lang("en"))
+        .lowercase()
+        .min_length(3)
+        .max_length(4)
+        .build()
+        .unwrap();
+    let tokens: Vec<Cow<str>> = pipeline.analyze(s).collect();
+    // "The" is not a stop word before lowercasing
+    assert_eq!(tokens, &["the", "sea"]);
+
+    // tokens that are not modified are not copied
+    let pipeline = TokenPipelineParams::default()
+        .tokenizer(RegexpTokenizer::default())
+        .lowercase()
+        .build()
+        .unwrap();
+    let tokens: Vec<Cow<str>> = pipeline.analyze("fish SEA").collect();
+    assert!(matches!(tokens[0], Cow::Borrowed("fish")));
+    assert!(matches!(tokens[1], Cow::Owned(_)));
+
+    let pipeline = TokenPipelineParams::<RegexpTokenizer>::default()
+        .regex_filter("(")
+        .build();
+    assert!(pipeline.is_err());
+}
+
+#[test]
+fn test_tokenize_with_offsets() {
+    let s = "Où va-t-il? Le “chat” n'a pas 3.5 ans.";
+
+    let tokenizers: Vec<Box<dyn Tokenizer>> = vec![
+        Box::new(RegexpTokenizer::default()),
+        Box::new(UnicodeWordTokenizer::default()),
+        Box::new(VTextTokenizerParams::default().lang("fr").build().unwrap()),
+        Box::new(CharacterTokenizer::default()),
+    ];
+    for tokenizer in tokenizers.iter() {
+        let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+
+        let offsets: Vec<Token> = tokenizer.tokenize_with_offsets(s).collect();
+        assert_eq!(offsets.len(), tokens.len());
+        for (token, expected) in offsets.iter().zip(tokens.iter()) {
+            assert_eq!(token.text, *expected);
+            assert_eq!(&s[token.start..token.end], token.text);
+        }
+
+        let chars: Vec<char> = s.chars().collect();
+        let offsets: Vec<Token> = tokenizer.tokenize_with_char_offsets(s).collect();
+        assert_eq!(offsets.len(), tokens.len());
+        for (token, expected) in offsets.iter().zip(tokens.iter()) {
+            assert_eq!(token.text, *expected);
+            let text: String = chars[token.start..token.end].iter().collect();
+            assert_eq!(text, token.text);
+        }
+    }
+
+    let tokenizer = UnicodeWordTokenizer::default();
+    let tokens: Vec<Token> = tokenizer.tokenize_with_offsets("“chat”").collect();
+    assert_eq!(
+        tokens,
+        &[
+            Token {
+                text: "“",
+                start: 0,
+                end: 3
+            },
+            Token {
+                text: "chat",
+                start: 3,
+                end: 7
+            },
+            Token {
+                text: "”",
+                start: 7,
+                end: 10
+            },
+        ]
+    );
+    let tokens: Vec<Token> = tokenizer.tokenize_with_char_offsets("“chat”").collect();
+    assert_eq!(
+        tokens,
+        &[
+            Token {
+                text: "“",
+                start: 0,
+                end: 1
+            },
+            Token {
+                text: "chat",
+                start: 1,
+                end: 5
+            },
+            Token {
+                text: "”",
+                start: 5,
+                end: 6
+            },
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "token \"<unk>\" is not a slice of the input text")]
+fn test_tokenize_with_offsets_owned_tokens() {
+    // tokens that are not slices of the input text
+    #[derive(Debug)]
+    struct LiteralTokenizer;
+
+    impl Tokenizer for LiteralTokenizer {
+        fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+            Box::new(text.split(' ').map(|x| if x == "le" { "<unk>" } else { x }))
+        }
+    }
+
+    let s = String::from("Le chat, le chat");
+    let _tokens: Vec<Token> = LiteralTokenizer.tokenize_with_offsets(&s).collect();
+}
+
+#[test]
+fn test_tokenizer_serde() {
+    let tokenizer = RegexpTokenizerParams::default()
+        .pattern(r"\w+")
+        .build()
+        .unwrap();
+    let serialized = serde_json::to_string(&tokenizer).unwrap();
+    assert_eq!(serialized, r#"{"pattern":"\\w+"}"#);
+    let tokenizer: RegexpTokenizer = serde_json::from_str(&serialized).unwrap();
+    let tokens: Vec<&str> = tokenizer.tokenize("a bc").collect();
+    assert_eq!(tokens, &["a", "bc"]);
+
+    // parameters are validated when deserializing
+    let res: Result<RegexpTokenizer, _> = serde_json::from_str(r#"{"pattern":"("}"#);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_character_tokenizer_serde() {
+    let tokenizer = CharacterTokenizerParams::default()
+        .window_size(3)
+        .build()
+        .unwrap();
+    let serialized = serde_json::to_string(&tokenizer).unwrap();
+    assert_eq!(serialized, r#"{"window_size":3,"ngram_range":null}"#);
+
+    // parameters serialized before ngram_range was added
+    let tokenizer: CharacterTokenizer = serde_json::from_str(r#"{"window_size":3}"#).unwrap();
+    let tokens: Vec<&str> = tokenizer.tokenize("fox").collect();
+    assert_eq!(tokens, &["fox"]);
+
+    let tokenizer = CharacterTokenizerParams::default()
+        .ngram_range(1, 2)
+        .build()
+        .unwrap();
+    let serialized = serde_json::to_string(&tokenizer).unwrap();
+    let tokenizer: CharacterTokenizer = serde_json::from_str(&serialized).unwrap();
+    let tokens: Vec<&str> = tokenizer.tokenize("ab").collect();
+    assert_eq!(tokens, &["a", "ab", "b"]);
+}
@@ -0,0 +1,38 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::errors::EstimatorErr;
+
+/// Built-in abbreviation lists, without the final period
+static ABBREVIATIONS: &[(&str, &str, &str)] = &[
+    ("en", "english", include_str!("abbreviations/english.txt")),
+    ("fr", "french", include_str!("abbreviations/french.txt")),
+    ("de", "german", include_str!("abbreviations/german.txt")),
+    ("it", "italian", include_str!("abbreviations/italian.txt")),
+    ("es", "spanish", include_str!("abbreviations/spanish.txt")),
+];
+
+/// Built-in abbreviation list for a language
+///
+/// `lang` can be either an ISO 639-1 code (e.g. "en") or the language name
+/// (e.g. "english"). Abbreviations are lowercase, without the final period.
+pub fn get_abbreviations(lang: &str) -> Result<Vec<String>, EstimatorErr> {
+    match ABBREVIATIONS
+        .iter()
+        .find(|(code, name, _)| *code == lang || *name == lang)
+    {
+        Some((_, _, text)) => Ok(text
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect()),
+        None => Err(EstimatorErr::InvalidParams(format!(
+            "abbreviations for lang={} are not available",
+            lang
+        ))),
+    }
+}
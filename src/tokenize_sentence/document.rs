@@ -0,0 +1,227 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::errors::EstimatorErr;
+use crate::tokenize::{Token, Tokenizer};
+use serde::{Deserialize, Serialize};
+
+/// Sentence with its position in the document, and its word tokens
+///
+/// The offsets of the sentence and of its tokens are relative to the document, either in bytes
+/// (`DocumentTokenizer::tokenize_sentences`) or in characters
+/// (`DocumentTokenizer::tokenize_sentences_with_char_offsets`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+    pub tokens: Vec<Token<'a>>,
+}
+
+/// Document tokenizer
+///
+/// Splits a document into sentences with a sentence tokenizer, then each sentence into words
+/// with a word tokenizer, keeping the offsets of both relative to the document.
+///
+/// As a `Tokenizer`, it returns the word tokens of all sentences.
+///
+/// ```rust
+/// use vtext::tokenize::*;
+/// use vtext::tokenize_sentence::*;
+///
+/// let tokenizer = DocumentTokenizerParams::default()
+///     .sentence_tokenizer(PunctuationTokenizer::default())
+///     .word_tokenizer(UnicodeWordTokenizer::default())
+///     .build()
+///     .unwrap();
+/// let sentences: Vec<Sentence> = tokenizer.tokenize_sentences("Hi there. Où va-t-il?").collect();
+/// assert_eq!(sentences[1].text, "Où va-t-il?");
+/// assert_eq!((sentences[1].start, sentences[1].end), (10, 22));
+/// assert_eq!(sentences[1].tokens[1], Token { text: "va", start: 14, end: 16 });
+///
+/// let sentences: Vec<Sentence> = tokenizer
+///     .tokenize_sentences_with_char_offsets("Hi there. Où va-t-il?")
+///     .collect();
+/// assert_eq!(sentences[1].tokens[1], Token { text: "va", start: 13, end: 15 });
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "DocumentTokenizerParams<S, W>",
+    into = "DocumentTokenizerParams<S, W>",
+    bound(
+        serialize = "S: Serialize + Tokenizer + Clone, W: Serialize + Tokenizer + Clone",
+        deserialize = "S: Deserialize<'de> + Tokenizer + Clone, \
+                       W: Deserialize<'de> + Tokenizer + Clone"
+    )
+)]
+pub struct DocumentTokenizer<S, W> {
+    pub params: DocumentTokenizerParams<S, W>,
+}
+
+/// Builder for the document tokenizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentTokenizerParams<S, W> {
+    sentence_tokenizer: S,
+    word_tokenizer: W,
+}
+
+impl<S: Tokenizer + Clone, W: Tokenizer + Clone> DocumentTokenizerParams<S, W> {
+    pub fn sentence_tokenizer(&mut self, value: S) -> DocumentTokenizerParams<S, W> {
+        self.sentence_tokenizer = value;
+        self.clone()
+    }
+    pub fn word_tokenizer(&mut self, value: W) -> DocumentTokenizerParams<S, W> {
+        self.word_tokenizer = value;
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<DocumentTokenizer<S, W>, EstimatorErr> {
+        Ok(DocumentTokenizer {
+            params: self.clone(),
+        })
+    }
+}
+
+impl<S: Tokenizer + Clone + Default, W: Tokenizer + Clone + Default> Default
+    for DocumentTokenizerParams<S, W>
+{
+    /// Create a new instance
+    fn default() -> DocumentTokenizerParams<S, W> {
+        DocumentTokenizerParams {
+            sentence_tokenizer: S::default(),
+            word_tokenizer: W::default(),
+        }
+    }
+}
+
+impl<S: Tokenizer + Clone + Default, W: Tokenizer + Clone + Default> Default
+    for DocumentTokenizer<S, W>
+{
+    /// Create a new instance
+    fn default() -> DocumentTokenizer<S, W> {
+        DocumentTokenizerParams::default().build().unwrap()
+    }
+}
+
+impl<S: Tokenizer + Clone, W: Tokenizer + Clone>
+    std::convert::TryFrom<DocumentTokenizerParams<S, W>> for DocumentTokenizer<S, W>
+{
+    type Error = EstimatorErr;
+
+    fn try_from(
+        mut params: DocumentTokenizerParams<S, W>,
+    ) -> Result<DocumentTokenizer<S, W>, EstimatorErr> {
+        params.build()
+    }
+}
+
+impl<S, W> From<DocumentTokenizer<S, W>> for DocumentTokenizerParams<S, W> {
+    fn from(tokenizer: DocumentTokenizer<S, W>) -> DocumentTokenizerParams<S, W> {
+        tokenizer.params
+    }
+}
+
+/// Convert the byte offset `byte_target` of `text` to a character offset
+///
+/// `byte_pos` and `char_pos` hold the last converted offset, so that characters are only counted
+/// once when offsets are increasing.
+fn to_char_offset(
+    text: &str,
+    byte_pos: &mut usize,
+    char_pos: &mut usize,
+    byte_target: usize,
+) -> usize {
+    if byte_target < *byte_pos {
+        *byte_pos = 0;
+        *char_pos = 0;
+    }
+    *char_pos += text[*byte_pos..byte_target].chars().count();
+    *byte_pos = byte_target;
+    *char_pos
+}
+
+impl<S: Tokenizer, W: Tokenizer> DocumentTokenizer<S, W> {
+    /// Tokenize a document into sentences of words, with byte offsets
+    pub fn tokenize_sentences<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Box<dyn Iterator<Item = Sentence<'a>> + 'a> {
+        Box::new(
+            self.params
+                .sentence_tokenizer
+                .tokenize_with_offsets(text)
+                .map(move |sentence| Sentence {
+                    text: sentence.text,
+                    start: sentence.start,
+                    end: sentence.end,
+                    tokens: self
+                        .params
+                        .word_tokenizer
+                        .tokenize_with_offsets(sentence.text)
+                        .map(|token| Token {
+                            text: token.text,
+                            start: sentence.start + token.start,
+                            end: sentence.start + token.end,
+                        })
+                        .collect(),
+                }),
+        )
+    }
+
+    /// Tokenize a document into sentences of words, with character offsets
+    ///
+    /// Offsets are counted in Unicode scalar values, which is how Python indexes strings.
+    pub fn tokenize_sentences_with_char_offsets<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Box<dyn Iterator<Item = Sentence<'a>> + 'a> {
+        let mut byte_pos = 0;
+        let mut char_pos = 0;
+        Box::new(self.tokenize_sentences(text).map(move |sentence| {
+            let start = to_char_offset(text, &mut byte_pos, &mut char_pos, sentence.start);
+            // Offsets of the tokens relative to the sentence
+            let (mut token_byte_pos, mut token_char_pos) = (0, 0);
+            let mut token_char_offset = |byte_offset: usize| {
+                start
+                    + to_char_offset(
+                        sentence.text,
+                        &mut token_byte_pos,
+                        &mut token_char_pos,
+                        byte_offset - sentence.start,
+                    )
+            };
+            let tokens = sentence
+                .tokens
+                .iter()
+                .map(|token| {
+                    let token_start = token_char_offset(token.start);
+                    Token {
+                        text: token.text,
+                        start: token_start,
+                        end: token_start + token.text.chars().count(),
+                    }
+                })
+                .collect();
+            Sentence {
+                text: sentence.text,
+                start,
+                end: start + sentence.text.chars().count(),
+                tokens,
+            }
+        }))
+    }
+}
+
+impl<S: Tokenizer, W: Tokenizer> Tokenizer for DocumentTokenizer<S, W> {
+    /// Tokenize a string into the words of all sentences
+    fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        Box::new(
+            self.params
+                .sentence_tokenizer
+                .tokenize(text)
+                .flat_map(move |sentence| self.params.word_tokenizer.tokenize(sentence)),
+        )
+    }
+}
@@ -55,6 +55,12 @@ let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
 assert_eq!(tokens, &["বৃহত্তম ভাষা। ", "বাংলা"]);
 ```
 
+Long documents can be split into paragraphs, or lines, with `ParagraphTokenizer` before
+sentence tokenization.
+
+`DocumentTokenizer` combines a sentence and a word tokenizer, returning sentences of word tokens
+with their offsets in the document.
+
 Refer to the [test cases](https://github.com/rth/vtext/blob/master/src/tokenize_sentence/tests.rs)
 for further langauge examples.
 
@@ -65,6 +71,8 @@ extern crate unicode_segmentation;
 
 #[cfg(feature = "python")]
 use dict_derive::{FromPyObject, IntoPyObject};
+use hashbrown::HashSet;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -73,9 +81,23 @@ use crate::tokenize::Tokenizer;
 
 use std::fmt;
 
+mod abbreviations;
+mod document;
+mod paragraph;
+mod punkt;
 #[cfg(test)]
 mod tests;
 
+pub use abbreviations::get_abbreviations;
+pub use document::{DocumentTokenizer, DocumentTokenizerParams, Sentence};
+pub use paragraph::{ParagraphTokenizer, ParagraphTokenizerParams};
+pub use punkt::{PunktSentenceTokenizer, PunktSentenceTokenizerParams};
+
+/// Closing quotes and brackets attached to the preceding sentence when they follow a terminator
+const CLOSING_PUNCTUATION: &[char] = &[
+    ')', ']', '}', '"', '\'', '»', '›', '”', '’', '」', '』', '）', '］', '｝',
+];
+
 /// Unicode sentence tokenizer
 ///
 /// This implementation is a thin wrapper around the
@@ -84,7 +106,11 @@ mod tests;
 /// ## References
 ///
 /// * [Unicode® Standard Annex #29](http://www.unicode.org/reports/tr29/)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "UnicodeSentenceTokenizerParams",
+    into = "UnicodeSentenceTokenizerParams"
+)]
 pub struct UnicodeSentenceTokenizer {
     pub params: UnicodeSentenceTokenizerParams,
 }
@@ -125,17 +151,47 @@ impl Tokenizer for UnicodeSentenceTokenizer {
 /// Punctuation sentence tokenizer
 ///
 /// This simple tokenizer uses punctuation (default ".", "?", "!") to determine sentence boundaries.
-/// Trailing whitespace is also captured in the preceding sentence.
+/// Closing quotes and brackets directly following the punctuation, as well as trailing whitespace,
+/// are also captured in the preceding sentence.
+///
+/// A period doesn't end a sentence when it is followed by a letter or a digit (e.g. "3.14"
+/// or "U.S"), when it is part of an ellipsis followed by a lowercase word, when it follows an
+/// initial (a single uppercase letter), or when it follows an abbreviation.
 ///
 /// # Arguments (PunctuationTokenizerParams)
 ///
-/// * `punctuation` - Punctuation tokens used to determine boundaries. Only the first "character"
-///                   using the `chars` method is used.
+/// * `punctuation` - Punctuation tokens used to determine boundaries. Multi-character tokens
+///   such as "?!" or "..." are matched as a whole, the longest matching token first.
+/// * `punctuation_pattern` - Optional regular expression, whose matches also determine boundaries.
+/// * `lang` - Use the built-in abbreviation list for this language, see `get_abbreviations`.
+/// * `abbreviations` - Custom abbreviations without the final period, matched case insensitively.
 ///
+/// ```rust
+/// use vtext::tokenize::Tokenizer;
+/// use vtext::tokenize_sentence::*;
 ///
-#[derive(Clone)]
+/// let tokenizer = PunctuationTokenizerParams::default().lang("en").build().unwrap();
+/// let tokens: Vec<&str> = tokenizer.tokenize("Dr. Smith paid $3.50 for it. OK").collect();
+/// assert_eq!(tokens, &["Dr. Smith paid $3.50 for it. ", "OK"]);
+///
+/// let tokenizer = PunctuationTokenizerParams::default()
+///     .punctuation(vtext::vecString!["?!", "!", "?", "."])
+///     .build()
+///     .unwrap();
+/// let tokens: Vec<&str> = tokenizer.tokenize("He asked \"Really?!\" (Yes.) OK").collect();
+/// assert_eq!(tokens, &["He asked \"Really?!\" ", "(Yes.) ", "OK"]);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "PunctuationTokenizerParams",
+    into = "PunctuationTokenizerParams"
+)]
 pub struct PunctuationTokenizer {
     pub params: PunctuationTokenizerParams,
+    abbreviations: HashSet<String>,
+    /// Punctuation tokens, longest first
+    terminators: Vec<String>,
+    regexp: Option<Regex>,
 }
 
 /// Builder for the punctuation sentence tokenizer
@@ -143,6 +199,9 @@ pub struct PunctuationTokenizer {
 #[cfg_attr(feature = "python", derive(FromPyObject, IntoPyObject))]
 pub struct PunctuationTokenizerParams {
     punctuation: Vec<String>,
+    punctuation_pattern: Option<String>,
+    lang: Option<String>,
+    abbreviations: Vec<String>,
 }
 
 impl PunctuationTokenizerParams {
@@ -150,9 +209,47 @@ impl PunctuationTokenizerParams {
         self.punctuation = punctuation;
         self.clone()
     }
+    /// Regular expression whose matches end a sentence, in addition to `punctuation`
+    pub fn punctuation_pattern(&mut self, value: &str) -> PunctuationTokenizerParams {
+        self.punctuation_pattern = Some(value.to_string());
+        self.clone()
+    }
+    /// Use the built-in abbreviation list for this language
+    pub fn lang(&mut self, value: &str) -> PunctuationTokenizerParams {
+        self.lang = Some(value.to_string());
+        self.clone()
+    }
+    /// Custom abbreviations, used in addition to the built-in list if `lang` is provided
+    pub fn abbreviations(&mut self, value: Vec<String>) -> PunctuationTokenizerParams {
+        self.abbreviations = value;
+        self.clone()
+    }
     pub fn build(&mut self) -> Result<PunctuationTokenizer, EstimatorErr> {
+        if self.punctuation.iter().any(|token| token.is_empty()) {
+            return Err(EstimatorErr::InvalidParams(
+                "punctuation tokens must not be empty".to_string(),
+            ));
+        }
+        let mut terminators = self.punctuation.clone();
+        terminators.sort_by_key(|terminator| std::cmp::Reverse(terminator.len()));
+        terminators.dedup();
+        let regexp = match &self.punctuation_pattern {
+            Some(pattern) => Some(Regex::new(pattern)?),
+            None => None,
+        };
+        let mut abbreviations: HashSet<String> = self
+            .abbreviations
+            .iter()
+            .map(|abbreviation| abbreviation.to_lowercase())
+            .collect();
+        if let Some(lang) = &self.lang {
+            abbreviations.extend(get_abbreviations(lang)?);
+        }
         Ok(PunctuationTokenizer {
             params: self.clone(),
+            abbreviations,
+            terminators,
+            regexp,
         })
     }
 }
@@ -171,6 +268,9 @@ impl Default for PunctuationTokenizerParams {
     fn default() -> PunctuationTokenizerParams {
         PunctuationTokenizerParams {
             punctuation: vecString![".", "!", "?"],
+            punctuation_pattern: None,
+            lang: None,
+            abbreviations: Vec::new(),
         }
     }
 }
@@ -186,8 +286,8 @@ impl fmt::Debug for PunctuationTokenizer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "PunctuationTokenizer {{ punctuation: {:#?} }}",
-            self.params.punctuation
+            "PunctuationTokenizer {{ punctuation: {:#?}, punctuation_pattern: {:?}, lang: {:?} }}",
+            self.params.punctuation, self.params.punctuation_pattern, self.params.lang
         )
     }
 }
@@ -197,7 +297,9 @@ impl Tokenizer for PunctuationTokenizer {
     fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
         Box::new(punctuation_sentence_iterator(
             text,
-            self.params.punctuation.clone(),
+            &self.terminators,
+            self.regexp.as_ref(),
+            &self.abbreviations,
         ))
     }
 }
@@ -205,18 +307,30 @@ impl Tokenizer for PunctuationTokenizer {
 // Builder for PunctuationTokenizerIterator
 fn punctuation_sentence_iterator<'a>(
     text: &'a str,
-    punctuation: Vec<String>,
+    terminators: &'a [String],
+    regexp: Option<&Regex>,
+    abbreviations: &'a HashSet<String>,
 ) -> PunctuationTokenizerIterator<'a> {
-    let punctuation_chars: Vec<char> = punctuation
-        .iter()
-        .map(|x| x.chars().next().unwrap())
-        .collect();
+    // Byte spans of the (non empty) matches of the punctuation pattern
+    let pattern_matches: Vec<(usize, usize)> = match regexp {
+        Some(regexp) => regexp
+            .find_iter(text)
+            .filter(|m| m.start() < m.end())
+            .map(|m| (m.start(), m.end()))
+            .collect(),
+        None => Vec::new(),
+    };
 
     PunctuationTokenizerIterator {
         text,
-        punctuation: punctuation_chars,
+        terminators,
+        pattern_matches,
+        pattern_idx: 0,
+        abbreviations,
         seen_punct: false,
+        seen_whitespace: false,
         i: 0,
+        skip_to: 0,
         span_end: 0,
         bytes_len: text.as_bytes().len(),
     }
@@ -225,9 +339,15 @@ fn punctuation_sentence_iterator<'a>(
 // PunctuationTokenizerIterator internal state
 struct PunctuationTokenizerIterator<'a> {
     text: &'a str,
-    punctuation: Vec<char>,
+    terminators: &'a [String],
+    pattern_matches: Vec<(usize, usize)>,
+    pattern_idx: usize,
+    abbreviations: &'a HashSet<String>,
     seen_punct: bool,
+    seen_whitespace: bool,
     i: usize,
+    // Byte index of the end of the last matched terminator
+    skip_to: usize,
     span_end: usize,
     bytes_len: usize,
 }
@@ -255,6 +375,66 @@ impl<'a> PunctuationTokenizerIterator<'a> {
         // View slice as str
         std::str::from_utf8(bytes_span).unwrap()
     }
+
+    // Byte index of the end of the longest terminator starting at byte index `idx`, if any
+    fn match_terminator(&mut self, idx: usize) -> Option<usize> {
+        let remaining_text = &self.text[idx..];
+        let mut end = self
+            .terminators
+            .iter()
+            .find(|terminator| remaining_text.starts_with(terminator.as_str()))
+            .map(|terminator| idx + terminator.len());
+
+        while let Some(&(start, match_end)) = self.pattern_matches.get(self.pattern_idx) {
+            if start > idx {
+                break;
+            }
+            self.pattern_idx += 1;
+            if start == idx && !matches!(end, Some(end) if end >= match_end) {
+                end = Some(match_end);
+            }
+        }
+        end
+    }
+
+    // Check if the terminator between byte indices `start` and `end` ends a sentence
+    fn is_boundary(&self, start: usize, end: usize) -> bool {
+        // Only a final period or ellipsis can be ambiguous
+        let character = match self.text[start..end].chars().last() {
+            Some(character) if character == '.' || character == '…' => character,
+            _ => return true,
+        };
+        let before = &self.text[..end - character.len_utf8()];
+        let after = &self.text[end..];
+
+        // Ellipsis, decimal numbers and periods inside a token such as "e.g" or "c.-à-d"
+        if let Some(next_char) = after.chars().next() {
+            if next_char == '.' || next_char == '-' || next_char.is_alphanumeric() {
+                return false;
+            }
+        }
+        // The last period of an ellipsis doesn't end a sentence followed by a lowercase word
+        if character == '…' || before.ends_with('.') {
+            return !after
+                .trim_start_matches(|c: char| c.is_whitespace() || CLOSING_PUNCTUATION.contains(&c))
+                .starts_with(char::is_lowercase);
+        }
+
+        // Word preceding the period, without opening quotes or brackets
+        let word = before
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or("")
+            .trim_start_matches(|c: char| !c.is_alphanumeric());
+        let mut chars = word.chars();
+        if let (Some(first), None) = (chars.next(), chars.next()) {
+            // Initials
+            if first.is_uppercase() {
+                return false;
+            }
+        }
+        !self.abbreviations.contains(&word.to_lowercase())
+    }
 }
 
 impl<'a> Iterator for PunctuationTokenizerIterator<'a> {
@@ -272,23 +452,28 @@ impl<'a> Iterator for PunctuationTokenizerIterator<'a> {
         for (i, character) in remaining_text.char_indices() {
             // idx_offset+i: bytes index of character
             self.i = i + idx_offset;
-            let is_punct = self.punctuation.contains(&character);
+            if self.i < self.skip_to {
+                // Inside a multi-character terminator
+                continue;
+            }
 
             if self.seen_punct {
-                let is_whitespace = character.is_whitespace();
-
-                if !is_whitespace {
+                if character.is_whitespace() {
+                    self.seen_whitespace = true;
+                } else if self.seen_whitespace || !CLOSING_PUNCTUATION.contains(&character) {
                     let span_start = self.span_end;
                     self.span_end = idx_offset + i;
                     self.seen_punct = false;
+                    self.seen_whitespace = false;
                     let span = self.bytes_slice(Some(span_start), Some(self.span_end));
                     if !span.is_empty() {
                         // Dont output if bytes represent 0 characters
                         return Some(span);
                     }
                 }
-            } else if is_punct {
-                self.seen_punct = true;
+            } else if let Some(end) = self.match_terminator(self.i) {
+                self.skip_to = end;
+                self.seen_punct = self.is_boundary(self.i, end);
             }
         }
 
@@ -306,3 +491,6 @@ impl<'a> Iterator for PunctuationTokenizerIterator<'a> {
         None
     }
 }
+
+impl_params_serde!(UnicodeSentenceTokenizer, UnicodeSentenceTokenizerParams);
+impl_params_serde!(PunctuationTokenizer, PunctuationTokenizerParams);
@@ -0,0 +1,142 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::errors::EstimatorErr;
+use crate::tokenize::Tokenizer;
+#[cfg(feature = "python")]
+use dict_derive::{FromPyObject, IntoPyObject};
+use serde::{Deserialize, Serialize};
+
+/// Paragraph tokenizer
+///
+/// Splits a document into paragraphs separated by blank lines (lines containing only
+/// whitespace), by the Unicode paragraph separator (U+2029) or by a form feed. Line breaks
+/// can be "\n", "\r\n", "\r", or the Unicode line separators (U+0085, U+2028, vertical tab),
+/// so that hard-wrapped paragraphs are kept together. Trailing whitespace is captured in the
+/// preceding paragraph.
+///
+/// This can be used to chunk long documents before sentence tokenization.
+///
+/// # Arguments (ParagraphTokenizerParams)
+///
+/// * `split_lines` - Split on each line break instead of blank lines (default false)
+///
+/// ```rust
+/// use vtext::tokenize::Tokenizer;
+/// use vtext::tokenize_sentence::*;
+///
+/// let s = "A hard-wrapped\r\nparagraph.\r\n\r\nAnother one.";
+/// let tokenizer = ParagraphTokenizer::default();
+/// let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+/// assert_eq!(tokens, &["A hard-wrapped\r\nparagraph.\r\n\r\n", "Another one."]);
+///
+/// let tokenizer = ParagraphTokenizerParams::default().split_lines(true).build().unwrap();
+/// let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+/// assert_eq!(tokens, &["A hard-wrapped\r\n", "paragraph.\r\n\r\n", "Another one."]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "ParagraphTokenizerParams",
+    into = "ParagraphTokenizerParams"
+)]
+pub struct ParagraphTokenizer {
+    pub params: ParagraphTokenizerParams,
+}
+
+/// Builder for the paragraph tokenizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", derive(FromPyObject, IntoPyObject))]
+pub struct ParagraphTokenizerParams {
+    split_lines: bool,
+}
+
+impl ParagraphTokenizerParams {
+    /// Split on each line break instead of blank lines
+    pub fn split_lines(&mut self, value: bool) -> ParagraphTokenizerParams {
+        self.split_lines = value;
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<ParagraphTokenizer, EstimatorErr> {
+        Ok(ParagraphTokenizer {
+            params: self.clone(),
+        })
+    }
+}
+
+impl Default for ParagraphTokenizerParams {
+    /// Create a new instance
+    fn default() -> ParagraphTokenizerParams {
+        ParagraphTokenizerParams { split_lines: false }
+    }
+}
+
+impl Default for ParagraphTokenizer {
+    /// Create a new instance
+    fn default() -> ParagraphTokenizer {
+        ParagraphTokenizerParams::default().build().unwrap()
+    }
+}
+
+impl Tokenizer for ParagraphTokenizer {
+    /// Tokenize a string
+    fn tokenize<'a>(&self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        Box::new(ParagraphTokenizerIterator {
+            text,
+            // Number of line breaks between two segments
+            min_line_breaks: if self.params.split_lines { 1 } else { 2 },
+            span_end: 0,
+        })
+    }
+}
+
+// ParagraphTokenizerIterator internal state
+struct ParagraphTokenizerIterator<'a> {
+    text: &'a str,
+    min_line_breaks: usize,
+    span_end: usize,
+}
+
+impl<'a> Iterator for ParagraphTokenizerIterator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let span_start = self.span_end;
+        if span_start >= self.text.len() {
+            return None;
+        }
+        let remaining_text = &self.text[span_start..];
+
+        // Count the line breaks in the whitespace following the last non whitespace character
+        let mut seen_text = false;
+        let mut line_breaks = 0;
+        let mut previous = None;
+        for (i, character) in remaining_text.char_indices() {
+            match character {
+                // Paragraph separator and form feed
+                '\u{2029}' | '\x0C' => line_breaks += 2,
+                // "\r\n" is a single line break
+                '\n' if previous == Some('\r') => {}
+                '\n' | '\r' | '\x0B' | '\u{85}' | '\u{2028}' => line_breaks += 1,
+                _ if character.is_whitespace() => {}
+                _ => {
+                    if seen_text && line_breaks >= self.min_line_breaks {
+                        self.span_end = span_start + i;
+                        return Some(&remaining_text[..i]);
+                    }
+                    seen_text = true;
+                    line_breaks = 0;
+                }
+            }
+            previous = Some(character);
+        }
+
+        // Trailing text
+        self.span_end = self.text.len();
+        Some(remaining_text)
+    }
+}
+
+impl_params_serde!(ParagraphTokenizer, ParagraphTokenizerParams);
@@ -0,0 +1,717 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+use crate::errors::EstimatorErr;
+use crate::persistence::{self, Format};
+use crate::tokenize::Tokenizer;
+#[cfg(feature = "python")]
+use dict_derive::{FromPyObject, IntoPyObject};
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+// Orthographic context of a type: its case at the beginning of sentences,
+// in the middle of sentences, or at an unknown position
+const ORTHO_BEG_UC: u8 = 1 << 1;
+const ORTHO_MID_UC: u8 = 1 << 2;
+const ORTHO_UNK_UC: u8 = 1 << 3;
+const ORTHO_BEG_LC: u8 = 1 << 4;
+const ORTHO_MID_LC: u8 = 1 << 5;
+const ORTHO_UNK_LC: u8 = 1 << 6;
+const ORTHO_UC: u8 = ORTHO_BEG_UC | ORTHO_MID_UC | ORTHO_UNK_UC;
+const ORTHO_LC: u8 = ORTHO_BEG_LC | ORTHO_MID_LC | ORTHO_UNK_LC;
+
+/// Type of all tokens that are numbers
+const NUMBER_TYPE: &str = "##number##";
+
+/// Characters that can't start a word, and are tokens on their own
+const NON_WORD_START: &[char] = &[
+    '(', '"', '`', '{', '[', ':', ';', '&', '#', '*', '@', ')', '}', ']', '-', ',',
+];
+
+/// Characters that end a word
+const NON_WORD: &[char] = &[
+    ')', '"', ';', '}', ']', '*', ':', '@', '\'', '(', '{', '[', '!', '?',
+];
+
+/// Punkt sentence tokenizer
+///
+/// Unsupervised sentence tokenizer, that learns from raw text which words ending with a period
+/// are abbreviations, which pairs of words are collocations (e.g. an ordinal number followed
+/// by a month), and which words frequently start sentences. Trailing whitespace is captured in
+/// the preceding sentence.
+///
+/// Without training, only the abbreviations given in `PunktSentenceTokenizerParams` are known.
+/// The trained tokenizer can be saved with `PunktSentenceTokenizer::save`.
+///
+/// ```rust
+/// use vtext::tokenize::Tokenizer;
+/// use vtext::tokenize_sentence::*;
+///
+/// let names = ["Smith", "Jones", "Brown", "Taylor", "Wilson"];
+/// let documents: Vec<String> = (0..50)
+///     .map(|i| {
+///         let (a, b) = (names[i % 5], names[(i + 1) % 5]);
+///         format!("Dr. {} met {}. Then {} was happy to see Dr. {}.", a, b, b, a)
+///     })
+///     .collect();
+///
+/// let mut tokenizer = PunktSentenceTokenizer::default();
+/// tokenizer.fit(&documents);
+/// assert!(tokenizer.abbreviations.contains("dr"));
+/// assert!(tokenizer.sentence_starters.contains("then"));
+///
+/// let tokens: Vec<&str> = tokenizer.tokenize("I met Dr. Brown. Then we left.").collect();
+/// assert_eq!(tokens, &["I met Dr. Brown. ", "Then we left."]);
+/// ```
+///
+/// ## References
+///
+/// * Kiss, Tibor and Strunk, Jan (2006): Unsupervised Multilingual Sentence Boundary
+///   Detection. Computational Linguistics 32: 485-525.
+/// * The NLTK implementation in `nltk.tokenize.punkt`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PunktSentenceTokenizer {
+    pub params: PunktSentenceTokenizerParams,
+    /// Abbreviations, lowercase without the final period
+    pub abbreviations: HashSet<String>,
+    /// Pairs of types without a sentence boundary between them
+    ///
+    /// The first type is an initial or a number (`##number##`) followed by a period.
+    pub collocations: HashSet<(String, String)>,
+    /// Lowercase types that frequently start sentences
+    pub sentence_starters: HashSet<String>,
+    /// Orthographic context of each lowercase type
+    ortho_context: HashMap<String, u8>,
+}
+
+/// Builder for the Punkt sentence tokenizer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", derive(FromPyObject, IntoPyObject))]
+pub struct PunktSentenceTokenizerParams {
+    abbreviations: Vec<String>,
+    abbreviation_threshold: f64,
+    collocation_threshold: f64,
+    sentence_starter_threshold: f64,
+}
+
+impl PunktSentenceTokenizerParams {
+    /// Known abbreviations without the final period, in addition to the learned ones
+    ///
+    /// For instance the built-in lists returned by `get_abbreviations`.
+    pub fn abbreviations(&mut self, value: Vec<String>) -> PunktSentenceTokenizerParams {
+        self.abbreviations = value;
+        self.clone()
+    }
+    /// Minimum score for a type to be learned as abbreviation (default 0.3)
+    pub fn abbreviation_threshold(&mut self, value: f64) -> PunktSentenceTokenizerParams {
+        self.abbreviation_threshold = value;
+        self.clone()
+    }
+    /// Minimum log-likelihood for a pair of types to be learned as collocation (default 7.88)
+    pub fn collocation_threshold(&mut self, value: f64) -> PunktSentenceTokenizerParams {
+        self.collocation_threshold = value;
+        self.clone()
+    }
+    /// Minimum log-likelihood for a type to be learned as sentence starter (default 30)
+    pub fn sentence_starter_threshold(&mut self, value: f64) -> PunktSentenceTokenizerParams {
+        self.sentence_starter_threshold = value;
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<PunktSentenceTokenizer, EstimatorErr> {
+        for (name, value) in [
+            ("abbreviation_threshold", self.abbreviation_threshold),
+            ("collocation_threshold", self.collocation_threshold),
+            (
+                "sentence_starter_threshold",
+                self.sentence_starter_threshold,
+            ),
+        ]
+        .iter()
+        {
+            if !(value.is_finite() && *value >= 0.0) {
+                return Err(EstimatorErr::InvalidParams(format!(
+                    "{}={} must be a positive number",
+                    name, value
+                )));
+            }
+        }
+        Ok(PunktSentenceTokenizer {
+            params: self.clone(),
+            abbreviations: self.known_abbreviations(),
+            collocations: HashSet::new(),
+            sentence_starters: HashSet::new(),
+            ortho_context: HashMap::new(),
+        })
+    }
+
+    fn known_abbreviations(&self) -> HashSet<String> {
+        self.abbreviations
+            .iter()
+            .map(|abbreviation| abbreviation.to_lowercase())
+            .collect()
+    }
+}
+
+impl Default for PunktSentenceTokenizerParams {
+    /// Create a new instance
+    fn default() -> PunktSentenceTokenizerParams {
+        PunktSentenceTokenizerParams {
+            abbreviations: Vec::new(),
+            abbreviation_threshold: 0.3,
+            collocation_threshold: 7.88,
+            sentence_starter_threshold: 30.0,
+        }
+    }
+}
+
+impl Default for PunktSentenceTokenizer {
+    /// Create a new instance
+    fn default() -> PunktSentenceTokenizer {
+        PunktSentenceTokenizerParams::default().build().unwrap()
+    }
+}
+
+/// Word token with the annotations used to detect sentence boundaries
+#[derive(Debug)]
+struct PunktToken<'a> {
+    text: &'a str,
+    /// Byte index of the end of the whitespace delimited chunk containing the token
+    chunk_end: usize,
+    /// Lowercase token, with numbers replaced by `NUMBER_TYPE`
+    typ: String,
+    period_final: bool,
+    parastart: bool,
+    linestart: bool,
+    sentbreak: bool,
+    abbr: bool,
+    ellipsis: bool,
+}
+
+impl<'a> PunktToken<'a> {
+    fn new(text: &'a str, chunk_end: usize, parastart: bool, linestart: bool) -> Self {
+        let typ = if _is_number(text) {
+            NUMBER_TYPE.to_string()
+        } else {
+            text.to_lowercase()
+        };
+        PunktToken {
+            text,
+            chunk_end,
+            typ,
+            period_final: text.ends_with('.'),
+            parastart,
+            linestart,
+            sentbreak: false,
+            abbr: false,
+            ellipsis: false,
+        }
+    }
+
+    /// Type without its final period
+    fn type_no_period(&self) -> &str {
+        if self.typ.len() > 1 && self.typ.ends_with('.') {
+            &self.typ[..self.typ.len() - 1]
+        } else {
+            &self.typ
+        }
+    }
+
+    /// Type without its final period if it ends a sentence
+    fn type_no_sentperiod(&self) -> &str {
+        if self.sentbreak {
+            self.type_no_period()
+        } else {
+            &self.typ
+        }
+    }
+
+    fn first_upper(&self) -> bool {
+        matches!(self.text.chars().next(), Some(c) if c.is_uppercase())
+    }
+
+    fn first_lower(&self) -> bool {
+        matches!(self.text.chars().next(), Some(c) if c.is_lowercase())
+    }
+
+    fn is_ellipsis(&self) -> bool {
+        self.text.len() > 1 && self.text.chars().all(|c| c == '.')
+    }
+
+    fn is_number(&self) -> bool {
+        self.typ.starts_with(NUMBER_TYPE)
+    }
+
+    /// A single letter followed by a period
+    fn is_initial(&self) -> bool {
+        let mut chars = self.text.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), Some('.'), None) => c.is_alphabetic(),
+            _ => false,
+        }
+    }
+
+    fn is_alpha(&self) -> bool {
+        self.text.chars().all(char::is_alphabetic)
+    }
+
+    fn is_non_punct(&self) -> bool {
+        self.typ.chars().any(char::is_alphabetic)
+    }
+}
+
+/// Check if a token is a number, such as "-1.5", "3." or "1,000"
+fn _is_number(token: &str) -> bool {
+    let token = token.strip_prefix('-').unwrap_or(token);
+    let token = token.strip_prefix(&['.', ','][..]).unwrap_or(token);
+    let mut chars = token.chars();
+    match chars.next() {
+        Some(c) if c.is_numeric() => {
+            chars.all(|c| c.is_numeric() || c == ',' || c == '.' || c == '-')
+        }
+        _ => false,
+    }
+}
+
+/// Length of a run of at least two periods or hyphens at the start of `text`
+fn _multi_char_punct(text: &str) -> Option<usize> {
+    let first = text.chars().next()?;
+    if first != '.' && first != '-' {
+        return None;
+    }
+    let length = text.chars().take_while(|c| *c == first).count();
+    if length >= 2 {
+        Some(length)
+    } else {
+        None
+    }
+}
+
+/// Split a whitespace delimited chunk into word tokens
+///
+/// Punctuation that can't be part of a word is split, while periods are kept attached to the
+/// preceding word, except in ellipses.
+fn _split_chunk(chunk: &str) -> Vec<(usize, usize)> {
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    let mut pos = 0;
+    while pos < chunk.len() {
+        let rest = &chunk[pos..];
+        if let Some(length) = _multi_char_punct(rest) {
+            spans.push((pos, pos + length));
+            pos += length;
+            continue;
+        }
+        let first = rest.chars().next().unwrap();
+        let mut end = pos + first.len_utf8();
+        if !NON_WORD_START.contains(&first) {
+            while end < chunk.len() {
+                let rest = &chunk[end..];
+                let c = rest.chars().next().unwrap();
+                if NON_WORD.contains(&c) || _multi_char_punct(rest).is_some() {
+                    break;
+                }
+                if c == ',' {
+                    // a comma ends a word if it ends the chunk or is followed by punctuation
+                    let after = &rest[1..];
+                    if after.is_empty()
+                        || after.starts_with(NON_WORD)
+                        || _multi_char_punct(after).is_some()
+                    {
+                        break;
+                    }
+                }
+                end += c.len_utf8();
+            }
+        }
+        spans.push((pos, end));
+        pos = end;
+    }
+    spans
+}
+
+/// Split a text into word tokens
+fn _word_tokenize(text: &str) -> Vec<PunktToken<'_>> {
+    let mut tokens = Vec::new();
+    let mut n_newlines = 0;
+    let mut chunk_start: Option<usize> = None;
+    for (idx, c) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+    {
+        if !c.is_whitespace() {
+            if chunk_start.is_none() {
+                chunk_start = Some(idx);
+            }
+            continue;
+        }
+        if let Some(start) = chunk_start.take() {
+            let chunk = &text[start..idx];
+            for (i, (span_start, span_end)) in _split_chunk(chunk).into_iter().enumerate() {
+                tokens.push(PunktToken::new(
+                    &chunk[span_start..span_end],
+                    idx,
+                    i == 0 && n_newlines > 1,
+                    i == 0 && n_newlines > 0,
+                ));
+            }
+            n_newlines = 0;
+        }
+        if c == '\n' {
+            n_newlines += 1;
+        }
+    }
+    tokens
+}
+
+/// Log-likelihood of a type being an abbreviation, with the Dunning log-likelihood ratio
+///
+/// Compares the probability of the period after the type with the overall probability of
+/// periods (null hypothesis), and with a probability of 0.99 (alternative hypothesis).
+fn _dunning_log_likelihood(count_a: f64, count_b: f64, count_ab: f64, n: f64) -> f64 {
+    let p1 = count_b / n;
+    let p2 = 0.99;
+    let null_hypo = _xlogy(count_ab, p1) + _xlogy(count_a - count_ab, 1.0 - p1);
+    let alt_hypo = _xlogy(count_ab, p2) + _xlogy(count_a - count_ab, 1.0 - p2);
+    -2.0 * (null_hypo - alt_hypo)
+}
+
+/// Log-likelihood of the collocation of two types `a` and `b`
+fn _col_log_likelihood(count_a: f64, count_b: f64, count_ab: f64, n: f64) -> f64 {
+    let p = count_b / n;
+    let p1 = count_ab / count_a;
+    let p2 = if n > count_a {
+        (count_b - count_ab) / (n - count_a)
+    } else {
+        1.0
+    };
+    let summand1 = _xlogy(count_ab, p) + _xlogy(count_a - count_ab, 1.0 - p);
+    let summand2 =
+        _xlogy(count_b - count_ab, p) + _xlogy(n - count_a - count_b + count_ab, 1.0 - p);
+    let summand3 = _xlogy(count_ab, p1) + _xlogy(count_a - count_ab, 1.0 - p1);
+    let summand4 =
+        _xlogy(count_b - count_ab, p2) + _xlogy(n - count_a - count_b + count_ab, 1.0 - p2);
+    -2.0 * (summand1 + summand2 - summand3 - summand4)
+}
+
+/// `x * ln(y)`, which is 0 when `x` is 0
+fn _xlogy(x: f64, y: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x * y.ln()
+    }
+}
+
+impl PunktSentenceTokenizer {
+    /// Learn abbreviations, collocations and sentence starters from documents
+    ///
+    /// Previously learned parameters are replaced. Documents should contain running text, and
+    /// the quality of the tokenizer improves with the size of the training corpus.
+    pub fn fit<I, D>(&mut self, X: I)
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str>,
+    {
+        let documents: Vec<D> = X.into_iter().collect();
+        self.abbreviations = self.params.known_abbreviations();
+        self.collocations.clear();
+        self.sentence_starters.clear();
+        self.ortho_context.clear();
+
+        let mut type_fdist: HashMap<String, usize> = HashMap::new();
+        let mut n_period_tokens: usize = 0;
+        for doc in documents.iter() {
+            for token in _word_tokenize(doc.as_ref()) {
+                if token.period_final {
+                    n_period_tokens += 1;
+                }
+                *type_fdist.entry(token.typ).or_insert(0) += 1;
+            }
+        }
+        let n_tokens: usize = type_fdist.values().sum();
+        self._learn_abbreviations(&type_fdist, n_tokens, n_period_tokens);
+
+        let mut n_sentbreaks: usize = 0;
+        let mut sent_starter_fdist: HashMap<String, usize> = HashMap::new();
+        let mut collocation_fdist: HashMap<(String, String), usize> = HashMap::new();
+        for doc in documents.iter() {
+            let mut tokens = _word_tokenize(doc.as_ref());
+            self._first_pass(&mut tokens);
+            self._learn_ortho_context(&tokens);
+            n_sentbreaks += tokens.iter().filter(|token| token.sentbreak).count();
+
+            for pair in tokens.windows(2) {
+                let (token, next_token) = (&pair[0], &pair[1]);
+                if !token.period_final {
+                    continue;
+                }
+                let is_number_or_initial = token.is_number() || token.is_initial();
+                if token.sentbreak && !is_number_or_initial && next_token.is_alpha() {
+                    *sent_starter_fdist
+                        .entry(next_token.typ.clone())
+                        .or_insert(0) += 1;
+                }
+                if token.sentbreak
+                    && is_number_or_initial
+                    && token.is_non_punct()
+                    && next_token.is_non_punct()
+                {
+                    let pair = (
+                        token.type_no_period().to_string(),
+                        next_token.type_no_sentperiod().to_string(),
+                    );
+                    *collocation_fdist.entry(pair).or_insert(0) += 1;
+                }
+            }
+        }
+        if n_sentbreaks == 0 {
+            return;
+        }
+
+        let type_count = |typ: &str| {
+            type_fdist.get(typ).unwrap_or(&0) + type_fdist.get(&format!("{}.", typ)).unwrap_or(&0)
+        };
+        let n = n_tokens as f64;
+        for (typ, count_at_break) in sent_starter_fdist {
+            let count = type_count(&typ);
+            if count < count_at_break {
+                continue;
+            }
+            let ll =
+                _col_log_likelihood(n_sentbreaks as f64, count as f64, count_at_break as f64, n);
+            if ll >= self.params.sentence_starter_threshold
+                && n / n_sentbreaks as f64 > count as f64 / count_at_break as f64
+            {
+                self.sentence_starters.insert(typ);
+            }
+        }
+        for ((typ1, typ2), count) in collocation_fdist {
+            if self.sentence_starters.contains(&typ2) {
+                continue;
+            }
+            let (count1, count2) = (type_count(&typ1), type_count(&typ2));
+            if count1 > 1 && count2 > 1 && 1 < count && count <= count1.min(count2) {
+                let ll = _col_log_likelihood(count1 as f64, count2 as f64, count as f64, n);
+                if ll >= self.params.collocation_threshold
+                    && n / count1 as f64 > count2 as f64 / count as f64
+                {
+                    self.collocations.insert((typ1, typ2));
+                }
+            }
+        }
+    }
+
+    /// Find the types that are frequently followed by a period
+    fn _learn_abbreviations(
+        &mut self,
+        type_fdist: &HashMap<String, usize>,
+        n_tokens: usize,
+        n_period_tokens: usize,
+    ) {
+        for typ in type_fdist.keys() {
+            if !typ.ends_with('.') || typ.len() < 2 || !typ.chars().any(char::is_alphabetic) {
+                continue;
+            }
+            let typ = &typ[..typ.len() - 1];
+            if self.abbreviations.contains(typ) {
+                continue;
+            }
+            let n_periods = typ.matches('.').count() + 1;
+            let n_non_periods = typ.chars().count() + 1 - n_periods;
+            let count_with_period = type_fdist[&format!("{}.", typ)];
+            let count_without_period = *type_fdist.get(typ).unwrap_or(&0);
+
+            let ll = _dunning_log_likelihood(
+                (count_with_period + count_without_period) as f64,
+                n_period_tokens as f64,
+                count_with_period as f64,
+                n_tokens as f64,
+            );
+            // long types, and types that often appear without a period, are penalized
+            let f_length = (-(n_non_periods as f64)).exp();
+            let f_penalty = (n_non_periods as f64).powi(-(count_without_period as i32));
+            let score = ll * f_length * n_periods as f64 * f_penalty;
+            if score >= self.params.abbreviation_threshold {
+                self.abbreviations.insert(typ.to_string());
+            }
+        }
+    }
+
+    /// Record the case of types at the beginning and in the middle of sentences
+    fn _learn_ortho_context(&mut self, tokens: &[PunktToken]) {
+        // context of the current token: 0 initial, 1 internal, 2 unknown
+        let mut context = 1;
+        for token in tokens {
+            if token.parastart && context != 2 {
+                context = 0;
+            }
+            if token.linestart && context == 1 {
+                context = 2;
+            }
+            let flag = match (context, token.first_upper(), token.first_lower()) {
+                (0, true, _) => ORTHO_BEG_UC,
+                (1, true, _) => ORTHO_MID_UC,
+                (2, true, _) => ORTHO_UNK_UC,
+                (0, _, true) => ORTHO_BEG_LC,
+                (1, _, true) => ORTHO_MID_LC,
+                (2, _, true) => ORTHO_UNK_LC,
+                _ => 0,
+            };
+            if flag != 0 {
+                *self
+                    .ortho_context
+                    .entry(token.type_no_sentperiod().to_string())
+                    .or_insert(0) |= flag;
+            }
+
+            context = if token.sentbreak {
+                if token.is_number() || token.is_initial() {
+                    2
+                } else {
+                    0
+                }
+            } else if token.ellipsis || token.abbr {
+                2
+            } else {
+                1
+            };
+        }
+    }
+
+    /// Annotate sentence breaks, abbreviations and ellipses from the token itself
+    fn _first_pass(&self, tokens: &mut [PunktToken]) {
+        for token in tokens.iter_mut() {
+            if token.text == "." || token.text == "?" || token.text == "!" {
+                token.sentbreak = true;
+            } else if token.is_ellipsis() {
+                token.ellipsis = true;
+            } else if token.period_final && !token.text.ends_with("..") {
+                let typ = token.text[..token.text.len() - 1].to_lowercase();
+                let last_part = typ.rsplit('-').next().unwrap_or("");
+                if self.abbreviations.contains(&typ) || self.abbreviations.contains(last_part) {
+                    token.abbr = true;
+                } else {
+                    token.sentbreak = true;
+                }
+            }
+        }
+    }
+
+    /// Decide if a token starts a sentence from its case, or `None` if unknown
+    fn _ortho_heuristic(&self, token: &PunktToken) -> Option<bool> {
+        if [";", ":", ",", ".", "!", "?"].contains(&token.text) {
+            return Some(false);
+        }
+        let ortho = *self
+            .ortho_context
+            .get(token.type_no_sentperiod())
+            .unwrap_or(&0);
+        if token.first_upper() && (ortho & ORTHO_LC) != 0 && (ortho & ORTHO_MID_UC) == 0 {
+            return Some(true);
+        }
+        if token.first_lower() && ((ortho & ORTHO_UC) != 0 || (ortho & ORTHO_BEG_LC) == 0) {
+            return Some(false);
+        }
+        None
+    }
+
+    /// Refine annotations of period final tokens with the following token
+    fn _second_pass(&self, tokens: &mut [PunktToken]) {
+        for idx in 1..tokens.len() {
+            let (before, after) = tokens.split_at_mut(idx);
+            let token = &mut before[idx - 1];
+            let next_token = &after[0];
+            if !token.period_final {
+                continue;
+            }
+            let typ = token.type_no_period().to_string();
+            let next_typ = next_token.type_no_sentperiod().to_string();
+            let is_initial = token.is_initial();
+
+            if self.collocations.contains(&(typ.clone(), next_typ.clone())) {
+                token.sentbreak = false;
+                token.abbr = true;
+                continue;
+            }
+            if (token.abbr || token.ellipsis) && !is_initial {
+                let is_sent_starter = self._ortho_heuristic(next_token);
+                if is_sent_starter == Some(true)
+                    || (next_token.first_upper() && self.sentence_starters.contains(&next_typ))
+                {
+                    token.sentbreak = true;
+                    continue;
+                }
+            }
+            if is_initial || typ == NUMBER_TYPE {
+                let is_sent_starter = self._ortho_heuristic(next_token);
+                let ortho = *self.ortho_context.get(&next_typ).unwrap_or(&0);
+                if is_sent_starter == Some(false)
+                    || (is_sent_starter.is_none()
+                        && is_initial
+                        && next_token.first_upper()
+                        && (ortho & ORTHO_LC) == 0)
+                {
+                    token.sentbreak = false;
+                    token.abbr = true;
+                }
+            }
+        }
+    }
+
+    /// Byte indices of the end of each sentence, including trailing whitespace
+    fn _sentence_ends(&self, text: &str) -> Vec<usize> {
+        let mut tokens = _word_tokenize(text);
+        self._first_pass(&mut tokens);
+        self._second_pass(&mut tokens);
+
+        let mut ends: Vec<usize> = Vec::new();
+        // the last token ends the text
+        for token in tokens.iter().rev().skip(1).rev() {
+            if !token.sentbreak {
+                continue;
+            }
+            let end = text.len() - text[token.chunk_end..].trim_start().len();
+            if ends.last() != Some(&end) {
+                ends.push(end);
+            }
+        }
+        if ends.last() != Some(&text.len()) {
+            ends.push(text.len());
+        }
+        ends
+    }
+}
+
+impl Tokenizer for PunktSentenceTokenizer {
+    /// Tokenize a string
+    fn tokenize<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        if text.is_empty() {
+            return Box::new(std::iter::empty());
+        }
+        let mut start = 0;
+        Box::new(self._sentence_ends(text).into_iter().map(move |end| {
+            let sentence = &text[start..end];
+            start = end;
+            sentence
+        }))
+    }
+}
+
+impl PunktSentenceTokenizer {
+    /// Save the tokenizer, including its parameters and learned data, to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: Format) -> Result<(), EstimatorErr> {
+        let file = File::create(path)?;
+        persistence::save(self, BufWriter::new(file), format)
+    }
+
+    /// Load a tokenizer saved with `PunktSentenceTokenizer::save`
+    pub fn load<P: AsRef<Path>>(path: P, format: Format) -> Result<Self, EstimatorErr> {
+        let file = File::open(path)?;
+        persistence::load(BufReader::new(file), format)
+    }
+}
@@ -4,13 +4,70 @@
 // <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
 // modified, or distributed except according to those terms.
 
-use crate::tokenize::Tokenizer;
+use crate::persistence::{self, Format};
+use crate::tokenize::{
+    RegexpTokenizer, RegexpTokenizerParams, Token, Tokenizer, UnicodeWordTokenizer,
+};
 
 use crate::tokenize_sentence::*;
 
 // macro located `vtext::tokenize_sentence::vecString`
 use crate::vecString;
 
+#[test]
+fn test_sentence_tokenize_with_offsets() {
+    let s = "Él está aquí. ¿Y tú? Sí!";
+
+    let tokenizer = PunctuationTokenizer::default();
+    let tokens: Vec<Token> = tokenizer.tokenize_with_offsets(s).collect();
+    assert_eq!(
+        tokens,
+        &[
+            Token {
+                text: "Él está aquí. ",
+                start: 0,
+                end: 17
+            },
+            Token {
+                text: "¿Y tú? ",
+                start: 17,
+                end: 26
+            },
+            Token {
+                text: "Sí!",
+                start: 26,
+                end: 30
+            },
+        ]
+    );
+    let tokens: Vec<Token> = tokenizer.tokenize_with_char_offsets(s).collect();
+    assert_eq!(
+        tokens,
+        &[
+            Token {
+                text: "Él está aquí. ",
+                start: 0,
+                end: 14
+            },
+            Token {
+                text: "¿Y tú? ",
+                start: 14,
+                end: 21
+            },
+            Token {
+                text: "Sí!",
+                start: 21,
+                end: 24
+            },
+        ]
+    );
+
+    let tokenizer = UnicodeSentenceTokenizer::default();
+    for token in tokenizer.tokenize_with_offsets(s) {
+        assert_eq!(&s[token.start..token.end], token.text);
+    }
+}
+
 #[test]
 fn test_punctuation_sentence_tokenizer() {
     let s = "Here is one. Here is another? Bang!! This trailing text is one more";
@@ -125,14 +182,13 @@ fn test_punctuation_sentence_tokenizer_multi_lang() {
     let expected = [
         "Die deutsche Sprache bzw. ",
         "Deutsch ([dɔʏ̯t͡ʃ]; abgekürzt dt. ",
-        "oder dtsch.",
-        ") ist eine westgermanische Sprache. ",
+        "oder dtsch.) ",
+        "ist eine westgermanische Sprache. ",
         "Ihr Sprachraum umfasst Deutschland, Österreich, die Deutschschweiz, Liechtenstein, \
         Luxemburg, Ostbelgien, Südtirol, das Elsass und Lothringen sowie Nordschleswig. ",
         "Außerdem ist sie eine Minderheitensprache in einigen europäischen und außereuropäischen \
         Ländern, z. ",
-        "B. ",
-        "in Rumänien und Südafrika, sowie Nationalsprache im afrikanischen Namibia",
+        "B. in Rumänien und Südafrika, sowie Nationalsprache im afrikanischen Namibia",
     ];
     let s = expected.join("");
     let tokens: Vec<&str> = tokenizer.tokenize(&s).collect();
@@ -160,14 +216,11 @@ fn test_punctuation_sentence_tokenizer_multi_lang() {
         ಅದರ ವಿವಿಧ ರೂಪಗಳಲ್ಲಿ ಸುಮಾರು ೪೫ ದಶಲಕ್ಷ ಜನರು ಆಡು ನುಡಿಯಾಗಿ ಬಳಸುತ್ತಲಿದ್ದಾರೆ. ",
         "ಕನ್ನಡ ಕರ್ನಾಟಕ ರಾಜ್ಯದ ಆಡಳಿತ ಭಾಷೆ.",
         "[೧೧] ಜಗತ್ತಿನಲ್ಲಿ ಅತ್ಯಂತ ಹೆಚ್ಚು ಮಂದಿ ಮಾತನಾಡುವ ಭಾಷೆಯೆಂಬ ನೆಲೆಯಲ್ಲಿ ಇಪ್ಪತೊಂಬತ್ತನೆಯ ಸ್ಥಾನ ಕನ್ನಡಕ್ಕಿದೆ. ",
-        "೨೦೧೧ರ ಜನಗಣತಿಯ ಪ್ರಕಾರ ಜಗತ್ತಿನಲ್ಲಿ ೬.",
-        "೪ ಕೋಟಿ ಜನಗಳು ಕನ್ನಡ ಮಾತನಾಡುತ್ತಾರೆ ಎಂದು ತಿಳಿದುಬಂದಿದೆ. ",
-        "ಇವರಲ್ಲಿ ೫.",
-        "೫ ಕೋಟಿ ಜನಗಳ ಮಾತೃಭಾಷೆ ಕನ್ನಡವಾಗಿದೆ. ",
+        "೨೦೧೧ರ ಜನಗಣತಿಯ ಪ್ರಕಾರ ಜಗತ್ತಿನಲ್ಲಿ ೬.೪ ಕೋಟಿ ಜನಗಳು ಕನ್ನಡ ಮಾತನಾಡುತ್ತಾರೆ ಎಂದು ತಿಳಿದುಬಂದಿದೆ. ",
+        "ಇವರಲ್ಲಿ ೫.೫ ಕೋಟಿ ಜನಗಳ ಮಾತೃಭಾಷೆ ಕನ್ನಡವಾಗಿದೆ. ",
         "ಬ್ರಾಹ್ಮಿ ಲಿಪಿಯಿಂದ ರೂಪುಗೊಂಡ ಕನ್ನಡ ಲಿಪಿಯನ್ನು ಉಪಯೋಗಿಸಿ ಕನ್ನಡ ಭಾಷೆಯನ್ನು ಬರೆಯಲಾಗುತ್ತದೆ. ",
         "ಕನ್ನಡ ಬರಹದ ಮಾದರಿಗಳಿಗೆ ಸಾವಿರದ ಐನೂರು ವರುಷಗಳ ಚರಿತ್ರೆಯಿದೆ. ",
-        "ಕ್ರಿ.",
-        "ಶ. ",
+        "ಕ್ರಿ.ಶ. ",
         "ಆರನೆಯ ಶತಮಾನದ ಪಶ್ಚಿಮ ಗಂಗ ಸಾಮ್ರಾಜ್ಯದ ಕಾಲದಲ್ಲಿ [೧೨] ಮತ್ತು ಒಂಬತ್ತನೆಯ ಶತಮಾನದ ರಾಷ್ಟ್ರಕೂಟ ಸಾಮ್ರಾಜ್ಯದ \
         ಕಾಲದಲ್ಲಿ ಹಳಗನ್ನಡ ಸಾಹಿತ್ಯ ಅತ್ಯಂತ ಹೆಚ್ಚಿನ ರಾಜಾಶ್ರಯ ಪಡೆಯಿತು.",
         "[೧೩][೧೪] ಅದಲ್ಲದೆ ಸಾವಿರ ವರುಷಗಳ ಸಾಹಿತ್ಯ ಪರಂಪರೆ ಕನ್ನಡಕ್ಕಿದೆ.",
@@ -211,12 +264,10 @@ fn test_punctuation_sentence_tokenizer_multi_lang() {
     // Telugu
     let expected = [
         "ఆంధ్ర ప్రదేశ్, తెలంగాణ రాష్ట్రాల అధికార భాష తెలుగు. ",
-        "భారత దేశంలో తెలుగు మాతృభాషగా మాట్లాడే 8.",
-        "7 కోట్ల (2001) జనాభాతో [1] ప్రాంతీయ భాషలలో మొదటి స్థానంలో ఉంది. ",
+        "భారత దేశంలో తెలుగు మాతృభాషగా మాట్లాడే 8.7 కోట్ల (2001) జనాభాతో [1] ప్రాంతీయ భాషలలో మొదటి స్థానంలో ఉంది. ",
         "ప్రపంచంలోని ప్రజలు అత్యధికముగా మాట్లాడే భాషలలో 15 స్థానములోనూ, భారత దేశములో హిందీ, తర్వాత \
         స్థానములోనూ నిలుస్తుంది. ",
-        "పాతవైన ప్రపంచ భాష గణాంకాల (ఎథ్నోలాగ్) ప్రకారం ప్రపంచవ్యాప్తంగా 7.",
-        "4 కోట్లు మందికి మాతృభాషగా ఉంది.",
+        "పాతవైన ప్రపంచ భాష గణాంకాల (ఎథ్నోలాగ్) ప్రకారం ప్రపంచవ్యాప్తంగా 7.4 కోట్లు మందికి మాతృభాషగా ఉంది.",
         "[2] మొదటి భాషగా మాట్లాడతారు. ",
         "అతి ప్రాచీన దేశ భాషలలో సంస్కృతము తమిళముతో బాటు తెలుగు భాషను 2008 అక్టోబరు 31న భారత ప్రభుత్వము \
         గుర్తించింది.",
@@ -230,9 +281,7 @@ fn test_punctuation_sentence_tokenizer_multi_lang() {
         "اُردُو لشکری زبان[8] (یا جدید معیاری اردو) برصغیر کی معیاری زبانوں میں سے ایک ہے۔ ",
         "یہ پاکستان کی قومی اور رابطہ عامہ کی زبان ہے، جبکہ بھارت کی چھے ریاستوں کی دفتری زبان کا درجہ رکھتی ہے۔ ",
         "آئین ہند کے مطابق اسے 22 دفتری شناخت زبانوں میں شامل کیا جاچکا ہے۔ ",
-        "2001ء کی مردم شماری کے مطابق اردو کو بطور مادری زبان بھارت میں 5.",
-        "01% فیصد لوگ بولتے ہیں اور اس لحاظ سے یہ بھارت کی چھٹی بڑی زبان ہے جبکہ پاکستان میں اسے بطور مادری زبان 7.",
-        "59% فیصد لوگ استعمال کرتے ہیں، یہ پاکستان کی پانچویں بڑی زبان ہے۔ ",
+        "2001ء کی مردم شماری کے مطابق اردو کو بطور مادری زبان بھارت میں 5.01% فیصد لوگ بولتے ہیں اور اس لحاظ سے یہ بھارت کی چھٹی بڑی زبان ہے جبکہ پاکستان میں اسے بطور مادری زبان 7.59% فیصد لوگ استعمال کرتے ہیں، یہ پاکستان کی پانچویں بڑی زبان ہے۔ ",
         "اردو تاریخی طور پر ہندوستان کی مسلم آبادی سے جڑی ہے۔",
         "[حوالہ درکار] بعض ذخیرہ الفاظ کے علاوہ یہ زبان معیاری ہندی سے قابل فہم ہے جو اس خطے کی ہندوؤں سے منسوب ہے۔",
         "[حوالہ درکار] زبانِ اردو کو پہچان و ترقی اس وقت ملی جب برطانوی دور میں انگریز حکمرانوں نے اسے فارسی کی بجائے انگریزی کے ساتھ شمالی ہندوستان کے علاقوں اور جموں و کشمیر میں اسے سنہ 1846ء اور پنجاب میں سنہ 1849ء میں بطور دفتری زبان نافذ کیا۔ ",
@@ -245,6 +294,383 @@ fn test_punctuation_sentence_tokenizer_multi_lang() {
     assert_eq!(tokens, expected);
 }
 
+#[test]
+fn test_punctuation_sentence_tokenizer_abbreviations() {
+    // English
+    let tokenizer = PunctuationTokenizerParams::default()
+        .lang("en")
+        .build()
+        .unwrap();
+    let expected = [
+        "Dr. Smith met J. R. R. Tolkien in the U.S. in 1937, e.g. at 3 p.m. on a Monday. ",
+        "It cost $3.50... or so. ",
+        "Wait... ",
+        "What? ",
+        "Mr. Brown arrived.",
+    ];
+    let s = expected.join("");
+    let tokens: Vec<&str> = tokenizer.tokenize(&s).collect();
+    assert_eq!(tokens, expected);
+
+    // French
+    let tokenizer = PunctuationTokenizerParams::default()
+        .lang("fr")
+        .build()
+        .unwrap();
+    let expected = [
+        "M. Dupont et Mme Martin sont arrivés à 10 h. ",
+        "Ils ont lu le chap. 3, c.-à-d. la fin du livre, p. ex. les notes. ",
+        "Le prix est de 2,5 millions.",
+    ];
+    let s = expected.join("");
+    let tokens: Vec<&str> = tokenizer.tokenize(&s).collect();
+    assert_eq!(tokens, expected);
+
+    // German
+    let tokenizer = PunctuationTokenizerParams::default()
+        .lang("german")
+        .build()
+        .unwrap();
+    let expected = [
+        "Die deutsche Sprache bzw. Deutsch ([dɔʏ̯t͡ʃ]; abgekürzt dt. oder dtsch.) ist eine \
+        westgermanische Sprache. ",
+        "Sie ist z. B. in Rumänien und Südafrika eine Minderheitensprache, vgl. Nr. 3 der Liste. ",
+        "Das kostet ca. 1.000 Euro usw. ",
+        "Ende",
+    ];
+    let s = expected.join("");
+    let tokens: Vec<&str> = tokenizer.tokenize(&s).collect();
+    assert_eq!(tokens, expected);
+
+    // Custom abbreviations are case insensitive, and used in addition to the built-in list
+    let tokenizer = PunctuationTokenizerParams::default()
+        .lang("en")
+        .abbreviations(vecString!["Approx", "Tel"])
+        .build()
+        .unwrap();
+    let tokens: Vec<&str> = tokenizer
+        .tokenize("Call tel. 555 at approx. noon. Dr. Who answers.")
+        .collect();
+    assert_eq!(
+        tokens,
+        &["Call tel. 555 at approx. noon. ", "Dr. Who answers."]
+    );
+
+    // Without abbreviations, only the language independent rules are applied
+    let tokenizer = PunctuationTokenizer::default();
+    let tokens: Vec<&str> = tokenizer.tokenize("See Fig. 3.2 by A. Smith.").collect();
+    assert_eq!(tokens, &["See Fig. ", "3.2 by A. Smith."]);
+
+    let res = PunctuationTokenizerParams::default().lang("xx").build();
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_punctuation_sentence_tokenizer_terminators() {
+    // Closing quotes and brackets belong to the preceding sentence
+    let tokenizer = PunctuationTokenizer::default();
+    let s = "He said \"Stop.\" Then he left. (It was late.) «Non!» Fin.";
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &[
+            "He said \"Stop.\" ",
+            "Then he left. ",
+            "(It was late.) ",
+            "«Non!» ",
+            "Fin."
+        ]
+    );
+    let tokens: Vec<&str> = tokenizer.tokenize("\"Wait...\" he said.").collect();
+    assert_eq!(tokens, &["\"Wait...\" he said."]);
+
+    // Multi-character terminators are matched as a whole, the longest first
+    let tokenizer = PunctuationTokenizerParams::default()
+        .punctuation(vecString!["?!", "!!", "...", "!", "?"])
+        .build()
+        .unwrap();
+    let s = "Really?! Yes!! Well... Maybe! No? End.";
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &["Really?! ", "Yes!! ", "Well... ", "Maybe! ", "No? ", "End."]
+    );
+
+    // Regex terminators, in addition to the punctuation
+    let tokenizer = PunctuationTokenizerParams::default()
+        .punctuation_pattern(r"[.!?]+|\n\s*[-*] ")
+        .build()
+        .unwrap();
+    let s = "Items:\n- first\n- second item!!! Done";
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &["Items:\n- ", "first\n- ", "second item!!! ", "Done"]
+    );
+
+    let res = PunctuationTokenizerParams::default()
+        .punctuation_pattern("(")
+        .build();
+    assert!(res.is_err());
+    let res = PunctuationTokenizerParams::default()
+        .punctuation(vecString![".", ""])
+        .build();
+    assert!(res.is_err());
+
+    // Parameters are serialized with the tokenizer
+    let tokenizer = PunctuationTokenizerParams::default()
+        .punctuation(vecString!["?!", "."])
+        .punctuation_pattern("\n\n")
+        .build()
+        .unwrap();
+    let mut buffer = Vec::new();
+    persistence::save(&tokenizer, &mut buffer, Format::Json).unwrap();
+    let tokenizer2: PunctuationTokenizer = persistence::load(&buffer[..], Format::Json).unwrap();
+    let s = "Why?! Because\n\nof this. Yes";
+    assert_eq!(
+        tokenizer.tokenize(s).collect::<Vec<_>>(),
+        tokenizer2.tokenize(s).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_punkt_sentence_tokenizer() {
+    let names = ["Anna", "Jonas", "Lena", "Paul", "Sophie"];
+    let months = ["Mai", "Juni", "Juli"];
+    let documents: Vec<String> = (0..60)
+        .map(|i| {
+            let (a, b) = (names[i % 5], names[(i + 2) % 5]);
+            format!(
+                "Am {}. {} traf {} Dr. {}. Danach ging {} mit {} nach Berlin. \
+                 {} war in Berlin mit Dr. {}.",
+                i % 28 + 1,
+                months[i % 3],
+                a,
+                b,
+                a,
+                b,
+                b,
+                a
+            )
+        })
+        .collect();
+    let s = "Am 5. Mai traf Paul Dr. Lena. Danach ging Anna nach Berlin. Es war spät.";
+
+    // without training, every period ends a sentence
+    let tokenizer = PunktSentenceTokenizer::default();
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(tokens.len(), 5);
+    assert_eq!(tokens.concat(), s);
+
+    let mut tokenizer = PunktSentenceTokenizer::default();
+    tokenizer.fit(&documents);
+    assert_eq!(
+        tokenizer.abbreviations.iter().collect::<Vec<&String>>(),
+        &["dr"]
+    );
+    assert!(tokenizer
+        .collocations
+        .contains(&("##number##".to_string(), "mai".to_string())));
+    assert!(tokenizer.sentence_starters.contains("danach"));
+    let expected = &[
+        "Am 5. Mai traf Paul Dr. Lena. ",
+        "Danach ging Anna nach Berlin. ",
+        "Es war spät.",
+    ];
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(tokens, expected);
+
+    // learned parameters are serialized
+    let mut buffer: Vec<u8> = Vec::new();
+    persistence::save(&tokenizer, &mut buffer, Format::Json).unwrap();
+    let tokenizer2: PunktSentenceTokenizer =
+        persistence::load(buffer.as_slice(), Format::Json).unwrap();
+    assert_eq!(tokenizer2.abbreviations, tokenizer.abbreviations);
+    assert_eq!(tokenizer2.collocations, tokenizer.collocations);
+    let tokens: Vec<&str> = tokenizer2.tokenize(s).collect();
+    assert_eq!(tokens, expected);
+}
+
+#[test]
+fn test_punkt_sentence_tokenizer_params() {
+    // known abbreviations are used with or without training
+    let tokenizer = PunktSentenceTokenizerParams::default()
+        .abbreviations(vecString!["Dr"])
+        .build()
+        .unwrap();
+    let tokens: Vec<&str> = tokenizer
+        .tokenize("Dr. Smith arrived (late). Really?! Yes... and done")
+        .collect();
+    assert_eq!(
+        tokens,
+        &["Dr. Smith arrived (late). ", "Really?! ", "Yes... and done"]
+    );
+    assert_eq!(tokenizer.tokenize("").count(), 0);
+
+    let res = PunktSentenceTokenizerParams::default()
+        .abbreviation_threshold(-1.0)
+        .build();
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_paragraph_tokenizer() {
+    let tokenizer = ParagraphTokenizer::default();
+
+    // Hard-wrapped paragraphs, blank lines with whitespace and CRLF line breaks
+    let s = "First paragraph,\nwrapped.\n  \t\nSecond\r\nparagraph.\r\n\r\n\r\nThird.\n";
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &[
+            "First paragraph,\nwrapped.\n  \t\n",
+            "Second\r\nparagraph.\r\n\r\n\r\n",
+            "Third.\n"
+        ]
+    );
+    // A single CRLF or CR is not a blank line
+    let tokens: Vec<&str> = tokenizer.tokenize("One\r\nline\rTwo").collect();
+    assert_eq!(tokens, &["One\r\nline\rTwo"]);
+
+    // Unicode separators and form feed
+    let s = "One\u{2029}Two\u{2028}lines\u{85}\u{85}Three\x0CFour";
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &[
+            "One\u{2029}",
+            "Two\u{2028}lines\u{85}\u{85}",
+            "Three\x0C",
+            "Four"
+        ]
+    );
+
+    // Leading whitespace is part of the first paragraph
+    let tokens: Vec<&str> = tokenizer.tokenize("\n\n  Text\n\n").collect();
+    assert_eq!(tokens, &["\n\n  Text\n\n"]);
+    assert_eq!(tokenizer.tokenize("").count(), 0);
+
+    let tokenizer = ParagraphTokenizerParams::default()
+        .split_lines(true)
+        .build()
+        .unwrap();
+    let s = "Line one\r\nline two\n\n\u{2028}line three";
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &["Line one\r\n", "line two\n\n\u{2028}", "line three"]
+    );
+    for token in tokenizer.tokenize_with_offsets(s) {
+        assert_eq!(&s[token.start..token.end], token.text);
+    }
+
+    // Paragraphs can be split into sentences
+    let tokenizer = ParagraphTokenizer::default();
+    let s = "Hello there. How are\nyou?\n\nFine.";
+    let sentence_tokenizer = PunctuationTokenizer::default();
+    let sentences: Vec<Vec<&str>> = tokenizer
+        .tokenize(s)
+        .map(|paragraph| sentence_tokenizer.tokenize(paragraph).collect())
+        .collect();
+    assert_eq!(
+        sentences,
+        &[vec!["Hello there. ", "How are\nyou?\n\n"], vec!["Fine."]]
+    );
+}
+
+#[test]
+fn test_document_tokenizer() {
+    let s = "Él está aquí. ¿Y tú? Sí!";
+    let tokenizer: DocumentTokenizer<PunctuationTokenizer, UnicodeWordTokenizer> =
+        DocumentTokenizer::default();
+
+    let sentences: Vec<Sentence> = tokenizer.tokenize_sentences(s).collect();
+    let texts: Vec<Vec<&str>> = sentences
+        .iter()
+        .map(|sentence| sentence.tokens.iter().map(|token| token.text).collect())
+        .collect();
+    assert_eq!(
+        texts,
+        &[
+            vec!["Él", "está", "aquí", "."],
+            vec!["¿", "Y", "tú", "?"],
+            vec!["Sí", "!"]
+        ]
+    );
+    assert_eq!(
+        sentences[1].tokens[2],
+        Token {
+            text: "tú",
+            start: 21,
+            end: 24
+        }
+    );
+    for sentence in sentences.iter() {
+        assert_eq!(&s[sentence.start..sentence.end], sentence.text);
+        for token in sentence.tokens.iter() {
+            assert_eq!(&s[token.start..token.end], token.text);
+        }
+    }
+
+    // Character offsets
+    let chars: Vec<char> = s.chars().collect();
+    let sentences: Vec<Sentence> = tokenizer.tokenize_sentences_with_char_offsets(s).collect();
+    assert_eq!((sentences[1].start, sentences[1].end), (14, 21));
+    assert_eq!(
+        sentences[1].tokens[2],
+        Token {
+            text: "tú",
+            start: 17,
+            end: 19
+        }
+    );
+    for sentence in sentences.iter() {
+        for token in sentence.tokens.iter() {
+            let text: String = chars[token.start..token.end].iter().collect();
+            assert_eq!(text, token.text);
+        }
+    }
+
+    // As a tokenizer, words of all sentences are returned
+    let tokens: Vec<&str> = tokenizer.tokenize(s).collect();
+    assert_eq!(
+        tokens,
+        &["Él", "está", "aquí", ".", "¿", "Y", "tú", "?", "Sí", "!"]
+    );
+    assert_eq!(tokenizer.tokenize_sentences("").count(), 0);
+
+    // Paragraphs of words, with another word tokenizer
+    let tokenizer = DocumentTokenizerParams::default()
+        .sentence_tokenizer(ParagraphTokenizer::default())
+        .word_tokenizer(
+            RegexpTokenizerParams::default()
+                .pattern(r"\w+")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let sentences: Vec<Sentence> = tokenizer.tokenize_sentences("a b\n\nc").collect();
+    assert_eq!(sentences.len(), 2);
+    assert_eq!(
+        sentences[1].tokens,
+        &[Token {
+            text: "c",
+            start: 5,
+            end: 6
+        }]
+    );
+
+    let mut buffer = Vec::new();
+    persistence::save(&tokenizer, &mut buffer, Format::Json).unwrap();
+    let tokenizer2: DocumentTokenizer<ParagraphTokenizer, RegexpTokenizer> =
+        persistence::load(&buffer[..], Format::Json).unwrap();
+    assert_eq!(
+        tokenizer2.tokenize("a b\n\nc").collect::<Vec<_>>(),
+        &["a", "b", "c"]
+    );
+}
+
 #[test]
 fn test_unicode_sentence_tokenizer_multi_lang() {
     // More complex unicode languages
@@ -21,30 +21,56 @@ let documents = vec![
 ];
 
 let mut vectorizer = CountVectorizer::<VTextTokenizer>::default();
-let X = vectorizer.fit_transform(&documents);
+let X = vectorizer.fit_transform(&documents).unwrap();
 // returns a sparse CSR matrix with document-terms counts
+```
+
+Token counts can be re-weighted with tf-idf using `TfidfVectorizer`, or
+equivalently, a `TfidfTransformer` applied to the output of `CountVectorizer`.
+
+```rust
+# use vtext::tokenize::VTextTokenizer;
+# use vtext::vectorize::*;
+# let documents = vec![String::from("Some text input"), String::from("Another line")];
+let mut vectorizer = TfidfVectorizerParams::<VTextTokenizer>::default()
+    .sublinear_tf(true)
+    .norm(Norm::L2)
+    .build()
+    .unwrap();
+let X = vectorizer.fit_transform(&documents).unwrap();
+// returns a sparse CSR matrix of f64 tf-idf weights
+```
 */
 
 use crate::errors::EstimatorErr;
 use crate::math::CSRArray;
-use crate::tokenize::Tokenizer;
+use crate::persistence::{self, Format};
+use crate::tokenize::{Analyzer, CaseFolding, StopWordFilter};
 
 use hashbrown::{HashMap, HashSet};
 use itertools::sorted;
 use ndarray::Array;
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
-use sprs::CsMat;
 #[cfg(feature = "rayon")]
-use std::cmp;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sprs::CsMat;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::Arc;
 
+mod murmurhash3;
 #[cfg(test)]
 mod tests;
 
 /// Sort features by name
 ///
 /// Returns a reordered matrix and modifies the vocabulary in place
-fn _sort_features(X: &mut CSRArray, vocabulary: &mut HashMap<String, i32>) {
+fn _sort_features<N>(X: &mut CSRArray<N>, vocabulary: &mut HashMap<String, i32>) {
     let mut vocabulary_sorted: Vec<_> = vocabulary
         .iter()
         .map(|(key, val)| (key.clone(), *val))
@@ -62,139 +88,871 @@ fn _sort_features(X: &mut CSRArray, vocabulary: &mut HashMap<String, i32>) {
     }
 }
 
+/// Select features by document frequency
+///
+/// Prunes features with a document frequency outside of `[min_df, max_df]`, then
+/// keeps at most `max_features` features with the highest corpus frequency `tf`.
+/// Returns the new index of each feature, preserving their order.
+fn _select_features(
+    df: &[usize],
+    tf: &[f64],
+    min_df: f64,
+    max_df: f64,
+    max_features: Option<usize>,
+) -> Vec<Option<usize>> {
+    let n_features = df.len();
+    let mut mask: Vec<bool> = df
+        .iter()
+        .map(|&df| (df as f64) >= min_df && (df as f64) <= max_df)
+        .collect();
+
+    if let Some(max_features) = max_features {
+        let mut kept: Vec<usize> = (0..n_features).filter(|idx| mask[*idx]).collect();
+        if kept.len() > max_features {
+            // sort by decreasing corpus frequency, ties are broken by feature index
+            kept.sort_by(|&a, &b| tf[b].partial_cmp(&tf[a]).unwrap().then(a.cmp(&b)));
+            for idx in kept[max_features..].iter() {
+                mask[*idx] = false;
+            }
+        }
+    }
+
+    let mut idx_map: Vec<Option<usize>> = vec![None; n_features];
+    let mut n_kept: usize = 0;
+    for (idx_old, keep) in mask.iter().enumerate() {
+        if *keep {
+            idx_map[idx_old] = Some(n_kept);
+            n_kept += 1;
+        }
+    }
+    idx_map
+}
+
+/// Re-index the vocabulary, and return the removed terms
+fn _remove_features(
+    vocabulary: &mut HashMap<String, i32>,
+    idx_map: &[Option<usize>],
+) -> HashSet<String> {
+    let mut removed_terms: HashSet<String> = HashSet::new();
+    vocabulary.retain(|term, idx| match idx_map[*idx as usize] {
+        Some(idx_new) => {
+            *idx = idx_new as i32;
+            true
+        }
+        None => {
+            removed_terms.insert(term.to_string());
+            false
+        }
+    });
+    removed_terms
+}
+
+/// Remove too rare or too common features
+///
+/// See `_select_features`, the corpus frequency is computed from `X`.
+/// The remaining features are re-indexed preserving their order, and the counts of
+/// removed features are moved to the out-of-vocabulary columns of `oov`, as in
+/// `CountVectorizer::transform`. Returns the removed terms.
+fn _limit_features<N: Scalar>(
+    X: &mut CSRArray<N>,
+    vocabulary: &mut HashMap<String, i32>,
+    min_df: f64,
+    max_df: f64,
+    max_features: Option<usize>,
+    oov: OovStrategy,
+) -> HashSet<String> {
+    let n_features = vocabulary.len();
+    let mut df: Vec<usize> = vec![0; n_features];
+    let mut tf: Vec<f64> = vec![0.0; n_features];
+    for (idx, value) in X.indices.iter().zip(X.data.iter()) {
+        df[*idx] += 1;
+        tf[*idx] += value.to_f64();
+    }
+
+    let idx_map = _select_features(&df, &tf, min_df, max_df, max_features);
+    let n_kept = idx_map.iter().flatten().count();
+    let mut oov_map: Vec<Option<usize>> = vec![None; n_features];
+    for (term, idx) in vocabulary.iter() {
+        if idx_map[*idx as usize].is_none() {
+            oov_map[*idx as usize] = oov.column(term).map(|column| n_kept + column);
+        }
+    }
+    let removed_terms = _remove_features(vocabulary, &idx_map);
+    if removed_terms.is_empty() {
+        return removed_terms;
+    }
+
+    let mut nnz: usize = 0;
+    let mut row_start: usize = 0;
+    let mut row_oov: Vec<(usize, N)> = Vec::new();
+    for row in 1..X.indptr.len() {
+        let row_end = X.indptr[row];
+        for pos in row_start..row_end {
+            if let Some(idx_new) = idx_map[X.indices[pos]] {
+                X.indices[nnz] = idx_new;
+                X.data[nnz] = X.data[pos];
+                nnz += 1;
+            } else if let Some(idx_new) = oov_map[X.indices[pos]] {
+                row_oov.push((idx_new, X.data[pos]));
+            }
+        }
+        // out-of-vocabulary columns come after the kept features
+        let row_start_new = X.indptr[row - 1];
+        row_oov.sort_unstable_by_key(|(idx, _)| *idx);
+        for (idx, value) in row_oov.drain(..) {
+            if nnz > row_start_new && X.indices[nnz - 1] == idx {
+                X.data[nnz - 1] = N::from_f64(X.data[nnz - 1].to_f64() + value.to_f64());
+            } else {
+                X.indices[nnz] = idx;
+                X.data[nnz] = value;
+                nnz += 1;
+            }
+        }
+        row_start = row_end;
+        X.indptr[row] = nnz;
+    }
+    X.indices.truncate(nnz);
+    X.data.truncate(nnz);
+
+    removed_terms
+}
+
 /// Sum duplicates
+///
+/// `indices_local` must be sorted by feature index. Negative values `!index` denote
+/// a feature with a negative sign (used for signed hashing), and features with a
+/// null sum are not stored. With `binary`, only the sign of the sum is stored.
 #[inline]
-fn _sum_duplicates(tf: &mut CSRArray, indices_local: &[i32], nnz: &mut usize) {
-    if !indices_local.is_empty() {
-        let mut bucket: i32 = 1;
-        let mut index_last = indices_local[0];
-
-        for index_current in indices_local.iter().skip(1) {
-            if *index_current != index_last {
+fn _sum_duplicates<N: Scalar>(
+    tf: &mut CSRArray<N>,
+    indices_local: &[i32],
+    nnz: &mut usize,
+    binary: bool,
+) {
+    let mut index_last: i32 = -1;
+    let mut bucket: i32 = 0;
+
+    for value in indices_local.iter() {
+        let (index, sign) = if *value < 0 {
+            (!*value, -1)
+        } else {
+            (*value, 1)
+        };
+        if index != index_last {
+            if bucket != 0 {
                 tf.indices.push(index_last as usize);
-                tf.data.push(bucket);
+                tf.data.push(_count_value(bucket, binary));
                 *nnz += 1;
-                index_last = *index_current;
-                bucket = 1;
-            } else {
-                bucket += 1;
             }
+            index_last = index;
+            bucket = 0;
         }
-        tf.indices
-            .push(indices_local[indices_local.len() - 1] as usize);
-        tf.data.push(bucket);
+        bucket += sign;
+    }
+    if bucket != 0 {
+        tf.indices.push(index_last as usize);
+        tf.data.push(_count_value(bucket, binary));
         *nnz += 1;
     }
 
     tf.indptr.push(*nnz);
 }
 
-#[derive(Debug, Clone)]
+#[inline]
+fn _count_value<N: Scalar>(count: i32, binary: bool) -> N {
+    if binary {
+        N::from_count(count.signum())
+    } else {
+        N::from_count(count)
+    }
+}
+
+/// Placeholder for the thread pool type, when vtext is built without rayon
+#[cfg(not(feature = "rayon"))]
+#[derive(Debug)]
+enum ThreadPool {}
+
+/// Create the thread pool used with `n_jobs > 1`, unless one is provided
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+fn _build_thread_pool(
+    n_jobs: usize,
+    thread_pool: &Option<Arc<ThreadPool>>,
+) -> Result<Option<Arc<ThreadPool>>, EstimatorErr> {
+    #[cfg(feature = "rayon")]
+    {
+        if n_jobs > 1 && thread_pool.is_none() {
+            let pool = ThreadPoolBuilder::new().num_threads(n_jobs).build()?;
+            return Ok(Some(Arc::new(pool)));
+        }
+    }
+    Ok(thread_pool.clone())
+}
+
+/// Thread pool used to process documents in parallel, if `n_jobs > 1`
+///
+/// The pool is created when building or loading the estimator, and is always
+/// `None` without the rayon feature.
+fn _parallel_pool(
+    thread_pool: &Option<Arc<ThreadPool>>,
+    n_jobs: usize,
+) -> Option<&Arc<ThreadPool>> {
+    thread_pool.as_ref().filter(|_| n_jobs > 1)
+}
+
+/// Number of documents processed by each job in a parallel chunk
+#[cfg(feature = "rayon")]
+const CHUNK_SIZE_PER_JOB: usize = 1000;
+
+/// Group documents into chunks of at most `chunk_size` documents
+#[cfg(feature = "rayon")]
+fn _chunks<I: Iterator>(mut documents: I, chunk_size: usize) -> impl Iterator<Item = Vec<I::Item>> {
+    std::iter::from_fn(move || {
+        let chunk: Vec<I::Item> = documents.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    })
+}
+
+/// Build a CSR array of counts from a stream of documents
+///
+/// `f` returns the sorted feature indices of a document. With a thread pool, documents are
+/// processed in parallel by chunks, and only one chunk is kept in memory at a time.
+fn _vectorize<N, I, D, F>(
+    X: I,
+    thread_pool: Option<&Arc<ThreadPool>>,
+    binary: bool,
+    f: F,
+) -> CSRArray<N>
+where
+    N: Scalar,
+    I: IntoIterator<Item = D>,
+    D: AsRef<str> + Send,
+    F: Fn(&str) -> Vec<i32> + Sync,
+{
+    let mut tf = CSRArray {
+        indices: Vec::new(),
+        indptr: vec![0],
+        data: Vec::new(),
+    };
+    let mut nnz: usize = 0;
+
+    match thread_pool {
+        #[cfg(feature = "rayon")]
+        Some(pool) => {
+            let chunk_size = pool.current_num_threads() * CHUNK_SIZE_PER_JOB;
+            for chunk in _chunks(X.into_iter(), chunk_size) {
+                let rows: Vec<Vec<i32>> =
+                    pool.install(|| chunk.into_par_iter().map(|doc| f(doc.as_ref())).collect());
+                for indices_local in rows {
+                    _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz, binary);
+                }
+            }
+        }
+        _ => {
+            for doc in X {
+                let indices_local = f(doc.as_ref());
+                _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz, binary);
+            }
+        }
+    }
+    tf
+}
+
+/// Tokenize a document, removing stop words if necessary
+#[inline]
+fn _tokenize<'a, T: Analyzer>(
+    tokenizer: &'a T,
+    stop_words: &'a Option<StopWordFilter>,
+    doc: &'a str,
+) -> Box<dyn Iterator<Item = Cow<'a, str>> + 'a> {
+    let tokens = tokenizer.analyze(doc);
+    match stop_words {
+        Some(filter) => Box::new(filter.filter(tokens)),
+        None => tokens,
+    }
+}
+
+/// Check that the number of jobs is valid
+fn _check_n_jobs(n_jobs: usize) -> Result<(), EstimatorErr> {
+    if n_jobs < 1 {
+        return Err(EstimatorErr::InvalidParams(format!(
+            "n_jobs={} must be > 0",
+            n_jobs
+        )));
+    }
+    if n_jobs > 1 && cfg!(not(feature = "rayon")) {
+        return Err(EstimatorErr::InvalidParams(format!(
+            "n_jobs={} requires vtext to be built with the rayon feature",
+            n_jobs
+        )));
+    }
+    Ok(())
+}
+
+/// Check that a vocabulary maps terms to unique and contiguous indices, starting from 0
+fn _check_vocabulary(vocabulary: &HashMap<String, i32>) -> Result<(), EstimatorErr> {
+    if vocabulary.is_empty() {
+        return Err(EstimatorErr::InvalidParams(
+            "vocabulary must not be empty".to_string(),
+        ));
+    }
+    let mut seen: Vec<bool> = vec![false; vocabulary.len()];
+    for (term, idx) in vocabulary.iter() {
+        if *idx < 0 || *idx as usize >= vocabulary.len() {
+            return Err(EstimatorErr::InvalidParams(format!(
+                "vocabulary index {} of {:?} must be in [0, {})",
+                idx,
+                term,
+                vocabulary.len()
+            )));
+        }
+        if seen[*idx as usize] {
+            return Err(EstimatorErr::InvalidParams(format!(
+                "vocabulary index {} is used more than once",
+                idx
+            )));
+        }
+        seen[*idx as usize] = true;
+    }
+    Ok(())
+}
+
+/// Check that the n-gram range is valid
+fn _check_ngram_range(ngram_range: (usize, usize)) -> Result<(), EstimatorErr> {
+    let (min_n, max_n) = ngram_range;
+    if min_n < 1 || min_n > max_n {
+        return Err(EstimatorErr::InvalidParams(format!(
+            "ngram_range=({}, {}) must verify 1 <= min_n <= max_n",
+            min_n, max_n
+        )));
+    }
+    Ok(())
+}
+
+/// Call `f` on each n-gram of the token sequence
+///
+/// N-grams of more than one token are joined with `separator` into `buffer`,
+/// which is re-used between n-grams to avoid allocating a new String for each of them.
+#[inline]
+fn _for_each_ngram<'a, F>(
+    tokens: impl Iterator<Item = Cow<'a, str>>,
+    ngram_range: (usize, usize),
+    separator: &str,
+    buffer: &mut String,
+    mut f: F,
+) where
+    F: FnMut(&str),
+{
+    let (min_n, max_n) = ngram_range;
+    if max_n == 1 {
+        // fast path for unigrams
+        for token in tokens {
+            f(&token);
+        }
+        return;
+    }
+    let tokens: Vec<Cow<str>> = tokens.collect();
+    for n in min_n..=max_n {
+        if n == 1 {
+            for token in tokens.iter() {
+                f(token);
+            }
+            continue;
+        }
+        for window in tokens.windows(n) {
+            buffer.clear();
+            buffer.push_str(&window[0]);
+            for token in &window[1..] {
+                buffer.push_str(separator);
+                buffer.push_str(token);
+            }
+            f(buffer);
+        }
+    }
+}
+
+/// Document frequency threshold
+///
+/// Either an absolute number of documents, or a proportion of documents
+/// in the range [0.0, 1.0].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DocumentFrequency {
+    Count(usize),
+    Ratio(f64),
+}
+
+impl DocumentFrequency {
+    /// Number of documents corresponding to this threshold
+    fn n_documents(&self, n_samples: usize) -> f64 {
+        match self {
+            DocumentFrequency::Count(count) => *count as f64,
+            DocumentFrequency::Ratio(ratio) => ratio * n_samples as f64,
+        }
+    }
+
+    fn validate(&self, name: &str) -> Result<(), EstimatorErr> {
+        match self {
+            DocumentFrequency::Ratio(ratio) if !(0.0..=1.0).contains(ratio) => {
+                Err(EstimatorErr::InvalidParams(format!(
+                    "{}={} must be in the range [0.0, 1.0]",
+                    name, ratio
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<usize> for DocumentFrequency {
+    fn from(value: usize) -> DocumentFrequency {
+        DocumentFrequency::Count(value)
+    }
+}
+
+impl From<f64> for DocumentFrequency {
+    fn from(value: f64) -> DocumentFrequency {
+        DocumentFrequency::Ratio(value)
+    }
+}
+
+/// Handling of out-of-vocabulary terms by the `CountVectorizer`
+///
+/// Extra columns for out-of-vocabulary terms are appended after the vocabulary.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OovStrategy {
+    /// Ignore out-of-vocabulary terms
+    Ignore,
+    /// Count all out-of-vocabulary terms in a single `<unk>` column
+    Unknown,
+    /// Hash out-of-vocabulary terms into this number of columns
+    Hash(u32),
+}
+
+impl OovStrategy {
+    /// Number of extra columns
+    fn n_features(self) -> usize {
+        match self {
+            OovStrategy::Ignore => 0,
+            OovStrategy::Unknown => 1,
+            OovStrategy::Hash(n_features) => n_features as usize,
+        }
+    }
+
+    /// Extra column of an out-of-vocabulary term, counted from the end of the vocabulary
+    #[inline]
+    fn column(self, term: &str) -> Option<usize> {
+        match self {
+            OovStrategy::Ignore => None,
+            OovStrategy::Unknown => Some(0),
+            OovStrategy::Hash(n_features) => {
+                let (index, _) = HashFunction::SeaHash.hash(term, 0, u64::from(n_features));
+                Some(index as usize)
+            }
+        }
+    }
+
+    /// Names of the extra columns
+    fn feature_names(self) -> Vec<String> {
+        match self {
+            OovStrategy::Ignore => Vec::new(),
+            OovStrategy::Unknown => vec!["<unk>".to_string()],
+            OovStrategy::Hash(n_features) => (0..n_features)
+                .map(|idx| format!("<unk-{}>", idx))
+                .collect(),
+        }
+    }
+
+    fn validate(self) -> Result<(), EstimatorErr> {
+        match self {
+            OovStrategy::Hash(n_features) if !(1..=1 << 30).contains(&n_features) => {
+                Err(EstimatorErr::InvalidParams(format!(
+                    "oov=Hash({}) must have between 1 and 2^30 columns",
+                    n_features
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountVectorizerParams<T> {
-    lowercase: bool,
+    case_folding: CaseFolding,
     tokenizer: T,
+    ngram_range: (usize, usize),
+    ngram_separator: String,
+    stop_words: Option<StopWordFilter>,
+    min_df: DocumentFrequency,
+    max_df: DocumentFrequency,
+    max_features: Option<usize>,
+    vocabulary: Option<HashMap<String, i32>>,
+    oov: OovStrategy,
+    binary: bool,
+    norm: Norm,
     n_jobs: usize,
+    #[serde(skip)]
+    thread_pool: Option<Arc<ThreadPool>>,
 }
 
-impl<T: Tokenizer + Clone> CountVectorizerParams<T> {
+impl<T: Analyzer + Clone> CountVectorizerParams<T> {
+    /// Convert all characters to lowercase before tokenizing
+    ///
+    /// This is a shortcut for `case_folding(CaseFolding::Lowercase)` when true and
+    /// `case_folding(CaseFolding::None)` when false.
     pub fn lowercase(&mut self, value: bool) -> CountVectorizerParams<T> {
-        self.lowercase = value;
+        self.case_folding = if value {
+            CaseFolding::Lowercase
+        } else {
+            CaseFolding::None
+        };
+        self.clone()
+    }
+    /// Case folding applied to documents before tokenizing
+    pub fn case_folding(&mut self, value: CaseFolding) -> CountVectorizerParams<T> {
+        self.case_folding = value;
         self.clone()
     }
     pub fn tokenizer(&mut self, value: T) -> CountVectorizerParams<T> {
         self.tokenizer = value;
         self.clone()
     }
+    /// The lower and upper boundary of the range of n-values for token n-grams to be extracted
+    pub fn ngram_range(&mut self, min_n: usize, max_n: usize) -> CountVectorizerParams<T> {
+        self.ngram_range = (min_n, max_n);
+        self.clone()
+    }
+    /// String used to join tokens in n-grams
+    pub fn ngram_separator(&mut self, value: &str) -> CountVectorizerParams<T> {
+        self.ngram_separator = value.to_string();
+        self.clone()
+    }
+    /// Remove stop words from the tokens, before n-grams are computed
+    pub fn stop_words(&mut self, value: StopWordFilter) -> CountVectorizerParams<T> {
+        self.stop_words = Some(value);
+        self.clone()
+    }
+    /// Ignore terms that have a document frequency strictly lower than this threshold
+    pub fn min_df<D: Into<DocumentFrequency>>(&mut self, value: D) -> CountVectorizerParams<T> {
+        self.min_df = value.into();
+        self.clone()
+    }
+    /// Ignore terms that have a document frequency strictly higher than this threshold
+    pub fn max_df<D: Into<DocumentFrequency>>(&mut self, value: D) -> CountVectorizerParams<T> {
+        self.max_df = value.into();
+        self.clone()
+    }
+    /// Only keep the `max_features` terms with the highest frequency across the corpus
+    pub fn max_features(&mut self, value: Option<usize>) -> CountVectorizerParams<T> {
+        self.max_features = value;
+        self.clone()
+    }
+    /// Use a fixed vocabulary, mapping terms to feature indices
+    ///
+    /// The built estimator is already fitted: `fit`, `fit_transform` and `partial_fit` don't
+    /// modify the vocabulary, and `min_df`, `max_df` and `max_features` are ignored.
+    /// Indices must be unique and contiguous, starting from 0.
+    pub fn vocabulary(&mut self, value: HashMap<String, i32>) -> CountVectorizerParams<T> {
+        self.vocabulary = Some(value);
+        self.clone()
+    }
+    /// Handling of out-of-vocabulary terms, `OovStrategy::Ignore` by default
+    ///
+    /// With `OovStrategy::Unknown` or `OovStrategy::Hash`, out-of-vocabulary terms are counted
+    /// in extra columns after the vocabulary. This includes terms removed by `min_df`, `max_df`
+    /// and `max_features`.
+    pub fn oov(&mut self, value: OovStrategy) -> CountVectorizerParams<T> {
+        self.oov = value;
+        self.clone()
+    }
+    /// Set all non zero counts to 1
+    ///
+    /// Document frequencies used for `min_df`, `max_df` and `max_features` are not affected.
+    pub fn binary(&mut self, value: bool) -> CountVectorizerParams<T> {
+        self.binary = value;
+        self.clone()
+    }
+    /// Normalization of each row of the output matrix
+    ///
+    /// Only supported with a floating point output, see `CountVectorizer::transform_as`.
+    pub fn norm(&mut self, value: Norm) -> CountVectorizerParams<T> {
+        self.norm = value;
+        self.clone()
+    }
+    /// Number of threads used to process documents in parallel
+    ///
+    /// With `n_jobs > 1`, a dedicated thread pool is created when building the estimator.
+    /// This replaces any thread pool set with `thread_pool`.
     pub fn n_jobs(&mut self, value: usize) -> CountVectorizerParams<T> {
         self.n_jobs = value;
+        self.thread_pool = None;
+        self.clone()
+    }
+    /// Process documents in parallel in an existing thread pool
+    ///
+    /// `n_jobs` is set to the number of threads of the pool.
+    #[cfg(feature = "rayon")]
+    pub fn thread_pool(&mut self, value: Arc<ThreadPool>) -> CountVectorizerParams<T> {
+        self.n_jobs = value.current_num_threads();
+        self.thread_pool = Some(value);
         self.clone()
     }
     pub fn build(&mut self) -> Result<CountVectorizer<T>, EstimatorErr> {
-        if self.n_jobs < 1 {
-            panic!("n_jobs={} must be > 0", self.n_jobs);
-        }
+        let params = self.clone().validate()?;
         Ok(CountVectorizer {
-            params: self.clone(),
-            vocabulary: HashMap::with_capacity_and_hasher(1000, Default::default()),
+            params,
+            vocabulary: match &self.vocabulary {
+                Some(vocabulary) => vocabulary.clone(),
+                None => HashMap::with_capacity_and_hasher(1000, Default::default()),
+            },
+            stop_words: HashSet::new(),
+            document_frequency: Vec::new(),
+            n_documents: 0,
+            frozen: self.vocabulary.is_some(),
         })
     }
 }
 
-impl<T: Tokenizer + Clone + Default> Default for CountVectorizerParams<T> {
+impl<T: Analyzer + Clone + Default> Default for CountVectorizerParams<T> {
     /// Create a new instance
     fn default() -> CountVectorizerParams<T> {
         let tokenizer = T::default();
         CountVectorizerParams {
-            lowercase: true,
+            case_folding: CaseFolding::Lowercase,
             tokenizer,
+            ngram_range: (1, 1),
+            ngram_separator: " ".to_string(),
+            stop_words: None,
+            min_df: DocumentFrequency::Count(1),
+            max_df: DocumentFrequency::Ratio(1.0),
+            max_features: None,
+            vocabulary: None,
+            oov: OovStrategy::Ignore,
+            binary: false,
+            norm: Norm::None,
             n_jobs: 1,
+            thread_pool: None,
+        }
+    }
+}
+
+impl<T> CountVectorizerParams<T> {
+    /// Check the parameters, and create the thread pool if necessary
+    fn validate(mut self) -> Result<CountVectorizerParams<T>, EstimatorErr> {
+        _check_n_jobs(self.n_jobs)?;
+        _check_ngram_range(self.ngram_range)?;
+        self.min_df.validate("min_df")?;
+        self.max_df.validate("max_df")?;
+        self.oov.validate()?;
+        if let Some(vocabulary) = &self.vocabulary {
+            _check_vocabulary(vocabulary)?;
         }
+        self.thread_pool = _build_thread_pool(self.n_jobs, &self.thread_pool)?;
+        Ok(self)
+    }
+
+    /// Whether document frequency or max_features pruning is enabled
+    fn prunes_features(&self) -> bool {
+        self.min_df != DocumentFrequency::Count(1)
+            || self.max_df != DocumentFrequency::Ratio(1.0)
+            || self.max_features.is_some()
     }
 }
 
-impl<T: Tokenizer + Clone + Default> Default for CountVectorizer<T> {
+impl<T: Analyzer + Clone + Default> Default for CountVectorizer<T> {
     /// Create a new instance
     fn default() -> CountVectorizer<T> {
         CountVectorizerParams::default().build().unwrap()
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(try_from = "CountVectorizerState<T>")]
 pub struct CountVectorizer<T> {
     pub params: CountVectorizerParams<T>,
     // vocabulary uses i32 indices, to avoid memory copies when converting
     // to sparse CSR arrays in Python with scipy.sparse
     pub vocabulary: HashMap<String, i32>,
+    /// Terms that were ignored because of `min_df`, `max_df` or `max_features`
+    ///
+    /// This is only useful for inspection, and corresponds to the `stop_words_`
+    /// attribute in scikit-learn.
+    pub stop_words: HashSet<String>,
+    /// Number of documents containing each feature, among documents seen by `partial_fit`
+    pub document_frequency: Vec<usize>,
+    /// Number of documents seen by `partial_fit`
+    pub n_documents: usize,
+    /// When true, `partial_fit` doesn't add new terms to the vocabulary
+    pub frozen: bool,
+}
+
+/// Serialized `CountVectorizer`, validated when loading
+#[derive(Deserialize)]
+struct CountVectorizerState<T> {
+    params: CountVectorizerParams<T>,
+    vocabulary: HashMap<String, i32>,
+    stop_words: HashSet<String>,
+    document_frequency: Vec<usize>,
+    n_documents: usize,
+    frozen: bool,
+}
+
+impl<T> std::convert::TryFrom<CountVectorizerState<T>> for CountVectorizer<T> {
+    type Error = EstimatorErr;
+
+    fn try_from(state: CountVectorizerState<T>) -> Result<CountVectorizer<T>, EstimatorErr> {
+        Ok(CountVectorizer {
+            params: state.params.validate()?,
+            vocabulary: state.vocabulary,
+            stop_words: state.stop_words,
+            document_frequency: state.document_frequency,
+            n_documents: state.n_documents,
+            frozen: state.frozen,
+        })
+    }
 }
 
 pub enum Vectorizer {}
 
-impl<T: Tokenizer + Sync> CountVectorizer<T> {
+impl<T: Analyzer + Sync> CountVectorizer<T> {
     /// Initialize a CountVectorizer estimator
+    ///
+    /// Returns an error if the parameters are invalid. The vocabulary is not validated,
+    /// and is replaced when fitting. Use `CountVectorizerParams::vocabulary` for a fixed
+    /// vocabulary.
     pub fn with_params_and_vocabulary(
         params: CountVectorizerParams<T>,
         vocabulary: HashMap<String, i32>,
-    ) -> Self {
-        CountVectorizer { params, vocabulary }
+    ) -> Result<Self, EstimatorErr> {
+        Ok(CountVectorizer {
+            params: params.validate()?,
+            vocabulary,
+            stop_words: HashSet::new(),
+            document_frequency: Vec::new(),
+            n_documents: 0,
+            frozen: false,
+        })
+    }
+
+    /// Add the terms of a document to a vocabulary
+    fn _extend_vocabulary(
+        &self,
+        vocabulary: &mut HashSet<String>,
+        doc: &str,
+        ngram_buffer: &mut String,
+    ) {
+        let doc = self.params.case_folding.apply(doc);
+        let tokens = _tokenize(&self.params.tokenizer, &self.params.stop_words, &doc);
+
+        _for_each_ngram(
+            tokens,
+            self.params.ngram_range,
+            &self.params.ngram_separator,
+            ngram_buffer,
+            |token| {
+                if !vocabulary.contains(token) {
+                    vocabulary.insert(token.to_string());
+                };
+            },
+        );
+    }
+
+    /// Compute the feature indices of a document into `indices_local`
+    ///
+    /// With `extend`, terms that are not in the vocabulary are added to it with the next
+    /// available index, otherwise they are ignored.
+    fn _index_document(
+        &self,
+        vocabulary: &mut HashMap<String, i32>,
+        doc: &str,
+        indices_local: &mut Vec<i32>,
+        ngram_buffer: &mut String,
+        extend: bool,
+    ) {
+        let doc = self.params.case_folding.apply(doc);
+        let tokens = _tokenize(&self.params.tokenizer, &self.params.stop_words, &doc);
+
+        indices_local.clear();
+
+        _for_each_ngram(
+            tokens,
+            self.params.ngram_range,
+            &self.params.ngram_separator,
+            ngram_buffer,
+            |token| {
+                match vocabulary.get(token) {
+                    Some(_id) => indices_local.push(*_id),
+                    None if extend => {
+                        let vocabulary_size = vocabulary.len() as i32;
+                        vocabulary.insert(token.to_string(), vocabulary_size);
+                        indices_local.push(vocabulary_size);
+                    }
+                    None => {}
+                };
+            },
+        );
     }
 
     /// Fit the estimator
     ///
-    /// This lists the vocabulary
-    pub fn fit(&mut self, X: &[String]) {
-        let tokenize = |X: &[String]| -> HashSet<String> {
-            let mut _vocab: HashSet<String> = HashSet::with_capacity(1000);
+    /// This lists the vocabulary. Documents can be provided by any iterator, and are
+    /// processed as a stream.
+    pub fn fit<I, D>(&mut self, X: I)
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        if self.params.vocabulary.is_some() {
+            // the vocabulary is fixed
+            return;
+        }
+        if self.params.prunes_features() {
+            // document frequencies are computed from the document-term matrix
+            self._fit_transform::<i32, _, _>(X);
+            return;
+        }
+        self.stop_words.clear();
+        self.document_frequency.clear();
+        self.n_documents = 0;
 
-            for doc in X {
-                let doc = doc.to_ascii_lowercase();
-                let tokens = self.params.tokenizer.tokenize(&doc);
+        let mut vocabulary: HashSet<String> = HashSet::with_capacity(1000);
 
-                for token in tokens {
-                    if !_vocab.contains(token) {
-                        _vocab.insert(token.to_string());
-                    };
+        match _parallel_pool(&self.params.thread_pool, self.params.n_jobs) {
+            #[cfg(feature = "rayon")]
+            Some(pool) => {
+                let chunk_size = pool.current_num_threads() * CHUNK_SIZE_PER_JOB;
+                for chunk in _chunks(X.into_iter(), chunk_size) {
+                    let chunk_vocabulary = pool.install(|| {
+                        chunk
+                            .into_par_iter()
+                            .fold(
+                                || (HashSet::new(), String::new()),
+                                |(mut vocabulary, mut ngram_buffer), doc| {
+                                    self._extend_vocabulary(
+                                        &mut vocabulary,
+                                        doc.as_ref(),
+                                        &mut ngram_buffer,
+                                    );
+                                    (vocabulary, ngram_buffer)
+                                },
+                            )
+                            .map(|(vocabulary, _)| vocabulary)
+                            .reduce(HashSet::new, |mut left, right| {
+                                left.extend(right);
+                                left
+                            })
+                    });
+                    vocabulary.extend(chunk_vocabulary);
                 }
             }
-            _vocab
-        };
-        let vocabulary: HashSet<String>;
-
-        if self.params.n_jobs == 1 {
-            vocabulary = tokenize(X);
-        } else if self.params.n_jobs > 1 {
-            #[cfg(not(feature = "rayon"))]
-            {
-                panic!("vtext not built with rayon support; got n_jobs > 1");
-            }
-
-            #[cfg(feature = "rayon")]
-            {
-                let chunk_size = cmp::max(X.len() / (self.params.n_jobs * 4), 1);
-                let pipe = X.par_chunks(chunk_size).flat_map(tokenize);
-                vocabulary = pipe.collect();
+            _ => {
+                let mut ngram_buffer = String::new();
+                for doc in X {
+                    self._extend_vocabulary(&mut vocabulary, doc.as_ref(), &mut ngram_buffer);
+                }
             }
-        } else {
-            panic!("n_jobs={} must be > 0", self.params.n_jobs);
         }
 
         if !vocabulary.is_empty() {
@@ -205,80 +963,295 @@ impl<T: Tokenizer + Sync> CountVectorizer<T> {
         }
     }
 
-    /// Transform
+    /// Incrementally fit the estimator
     ///
-    /// Converts a sequence of text documents to a CSR Matrix
-    pub fn transform(&mut self, X: &[String]) -> CsMat<i32> {
-        let mut tf = crate::math::CSRArray {
-            indices: Vec::new(),
-            indptr: Vec::new(),
-            data: Vec::new(),
-        };
-
-        tf.indptr.push(0);
-
-        let mut nnz: usize = 0;
-
-        let tokenize_map = |doc: &str| -> Vec<i32> {
-            // Closure to tokenize a document and returns hash indices for each token
-
-            let mut indices_local: Vec<i32> = Vec::with_capacity(10);
-
-            for token in self.params.tokenizer.tokenize(doc) {
-                if let Some(_id) = self.vocabulary.get(token) {
-                    indices_local.push(*_id)
-                };
-            }
-            // this takes 10-15% of the compute time
-            indices_local.sort_unstable();
-            indices_local
-        };
-        let pipe: Box<dyn Iterator<Item = Vec<i32>>>;
+    /// Extends the existing vocabulary with the terms of the documents: new terms are
+    /// appended with the next available indices, and existing indices are unchanged.
+    /// The document frequency of each term is tracked in `document_frequency`, and can
+    /// be used to prune the vocabulary with `prune`; only documents seen by `partial_fit`
+    /// are counted. When `frozen` is true, or with a fixed vocabulary, only document
+    /// frequencies of existing terms are updated.
+    ///
+    /// Contrary to `fit`, the vocabulary is not sorted by term. Documents are processed
+    /// sequentially.
+    pub fn partial_fit<I, D>(&mut self, X: I)
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str>,
+    {
+        let mut vocabulary = std::mem::take(&mut self.vocabulary);
+        let mut document_frequency = std::mem::take(&mut self.document_frequency);
+        document_frequency.resize(vocabulary.len(), 0);
 
-        if self.params.n_jobs == 1 {
-            pipe = Box::new(
-                X.iter()
-                    .map(|doc| doc.to_ascii_lowercase())
-                    .map(|doc| tokenize_map(&doc)),
+        let mut indices_local: Vec<i32> = Vec::new();
+        let mut ngram_buffer = String::new();
+        for doc in X {
+            self._index_document(
+                &mut vocabulary,
+                doc.as_ref(),
+                &mut indices_local,
+                &mut ngram_buffer,
+                !self.frozen && self.params.vocabulary.is_none(),
             );
-        } else if self.params.n_jobs > 1 {
-            #[cfg(not(feature = "rayon"))]
-            {
-                panic!("vtext not built with rayon support; got n_jobs > 1");
+            indices_local.sort_unstable();
+            indices_local.dedup();
+            document_frequency.resize(vocabulary.len(), 0);
+            for idx in indices_local.iter() {
+                document_frequency[*idx as usize] += 1;
             }
+            self.n_documents += 1;
+        }
 
-            #[cfg(feature = "rayon")]
-            {
-                pipe = Box::new(
-                    X.par_iter()
-                        .map(|doc| doc.to_ascii_lowercase())
-                        .map(|doc| tokenize_map(&doc))
-                        .collect::<Vec<Vec<i32>>>()
-                        .into_iter(),
-                );
+        self.vocabulary = vocabulary;
+        self.document_frequency = document_frequency;
+    }
+
+    /// Prune the vocabulary with the document frequencies tracked by `partial_fit`
+    ///
+    /// Removes terms with a document frequency outside of `[min_df, max_df]`, then keeps
+    /// the `max_features` terms with the highest document frequency. Remaining terms are
+    /// re-indexed preserving their order, and removed terms are added to `stop_words`.
+    pub fn prune(&mut self) -> Result<(), EstimatorErr> {
+        if self.params.vocabulary.is_some() {
+            return Err(EstimatorErr::InvalidParams(
+                "a fixed vocabulary cannot be pruned".to_string(),
+            ));
+        }
+        if self.n_documents == 0 {
+            return Err(EstimatorErr::NotFitted(
+                "CountVectorizer with partial_fit".to_string(),
+            ));
+        }
+        // in case the vocabulary was extended since the last partial_fit
+        self.document_frequency.resize(self.vocabulary.len(), 0);
+
+        let df_total: Vec<f64> = self
+            .document_frequency
+            .iter()
+            .map(|df| *df as f64)
+            .collect();
+        let idx_map = _select_features(
+            &self.document_frequency,
+            &df_total,
+            self.params.min_df.n_documents(self.n_documents),
+            self.params.max_df.n_documents(self.n_documents),
+            self.params.max_features,
+        );
+        let removed_terms = _remove_features(&mut self.vocabulary, &idx_map);
+
+        let mut document_frequency = vec![0; self.vocabulary.len()];
+        for (idx_old, idx_new) in idx_map.iter().enumerate() {
+            if let Some(idx_new) = idx_new {
+                document_frequency[*idx_new] = self.document_frequency[idx_old];
             }
-        } else {
-            panic!("n_jobs={} must be > 0", self.params.n_jobs);
         }
+        self.document_frequency = document_frequency;
+        self.stop_words.extend(removed_terms);
+        Ok(())
+    }
 
-        for indices_local in pipe {
-            _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz);
-        }
+    /// Number of columns of the document-term matrix
+    ///
+    /// This is the size of the vocabulary, plus the out-of-vocabulary columns of `oov`.
+    pub fn n_features(&self) -> usize {
+        self.vocabulary.len() + self.params.oov.n_features()
+    }
 
-        CsMat::new(
-            (tf.indptr.len() - 1, self.vocabulary.len()),
-            tf.indptr,
-            tf.indices,
-            tf.data,
-        )
+    /// Terms of the vocabulary, ordered by feature index
+    ///
+    /// Out-of-vocabulary columns are named `<unk>` with `OovStrategy::Unknown`,
+    /// and `<unk-0>`, `<unk-1>`, etc. with `OovStrategy::Hash`.
+    pub fn get_feature_names(&self) -> Vec<String> {
+        let mut features: Vec<(&String, &i32)> = self.vocabulary.iter().collect();
+        features.sort_unstable_by_key(|(_, idx)| **idx);
+        features
+            .into_iter()
+            .map(|(term, _)| term.clone())
+            .chain(self.params.oov.feature_names())
+            .collect()
     }
 
-    /// Fit and transform
+    /// Terms with a non zero value in each row of a document-term matrix
     ///
-    /// This is a single pass vectorization
-    pub fn fit_transform(&mut self, X: &[String]) -> CsMat<i32> {
-        let mut tf = crate::math::CSRArray {
-            indices: Vec::new(),
+    /// Terms are ordered by feature index. Returns an error if the number of
+    /// columns doesn't match `n_features`.
+    pub fn inverse_transform<N: Scalar>(
+        &self,
+        X: &CsMat<N>,
+    ) -> Result<Vec<Vec<String>>, EstimatorErr> {
+        if X.cols() != self.n_features() {
+            return Err(EstimatorErr::InvalidParams(format!(
+                "input has {} features, while the vectorizer has {} features",
+                X.cols(),
+                self.n_features()
+            )));
+        }
+        if !X.is_csr() {
+            return self.inverse_transform(&X.to_csr());
+        }
+        let feature_names = self.get_feature_names();
+        Ok(X.outer_iterator()
+            .map(|row| {
+                row.iter()
+                    .filter(|(_, value)| value.to_f64() != 0.0)
+                    .map(|(idx, _)| feature_names[idx].clone())
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Transform
+    ///
+    /// Converts a sequence of text documents to a CSR Matrix
+    ///
+    /// Returns an error if `norm` is set, as integer counts cannot be normalized. Use
+    /// `transform_as` with a floating point output type instead.
+    pub fn transform<I, D>(&mut self, X: I) -> Result<CsMat<i32>, EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        self.transform_as(X)
+    }
+
+    /// Transform, with values of type `N`
+    ///
+    /// Values are computed directly in the output type, without intermediary
+    /// copies. Returns an error if `norm` is set and `N` is not a floating point type.
+    pub fn transform_as<N, I, D>(&self, X: I) -> Result<CsMat<N>, EstimatorErr>
+    where
+        N: Scalar,
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        _check_norm::<N>(self.params.norm)?;
+
+        let n_vocabulary = self.vocabulary.len();
+        let tokenize_map = |doc: &str| -> Vec<i32> {
+            // Closure to tokenize a document and returns hash indices for each token
+
+            let doc = self.params.case_folding.apply(doc);
+            let mut indices_local: Vec<i32> = Vec::with_capacity(10);
+            let mut ngram_buffer = String::new();
+
+            _for_each_ngram(
+                _tokenize(&self.params.tokenizer, &self.params.stop_words, &doc),
+                self.params.ngram_range,
+                &self.params.ngram_separator,
+                &mut ngram_buffer,
+                |token| match self.vocabulary.get(token) {
+                    Some(_id) => indices_local.push(*_id),
+                    None => {
+                        if let Some(column) = self.params.oov.column(token) {
+                            indices_local.push((n_vocabulary + column) as i32)
+                        }
+                    }
+                },
+            );
+            // this takes 10-15% of the compute time
+            indices_local.sort_unstable();
+            indices_local
+        };
+
+        let mut tf = _vectorize(
+            X,
+            _parallel_pool(&self.params.thread_pool, self.params.n_jobs),
+            self.params.binary,
+            tokenize_map,
+        );
+        _normalize_rows(&tf.indptr, &mut tf.data, self.params.norm);
+
+        Ok(CsMat::new(
+            (tf.indptr.len() - 1, self.n_features()),
+            tf.indptr,
+            tf.indices,
+            tf.data,
+        ))
+    }
+
+    /// Proportion of out-of-vocabulary terms in each document
+    ///
+    /// Terms are extracted as in `transform`, including n-grams, and documents without
+    /// any term have a rate of 0. This doesn't depend on `oov`, and can be used to monitor
+    /// the drift between the training corpus and new documents.
+    pub fn oov_rate<I, D>(&self, X: I) -> Vec<f64>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str>,
+    {
+        let mut ngram_buffer = String::new();
+        X.into_iter()
+            .map(|doc| {
+                let doc = self.params.case_folding.apply(doc.as_ref());
+                let mut n_terms: usize = 0;
+                let mut n_oov: usize = 0;
+                _for_each_ngram(
+                    _tokenize(&self.params.tokenizer, &self.params.stop_words, &doc),
+                    self.params.ngram_range,
+                    &self.params.ngram_separator,
+                    &mut ngram_buffer,
+                    |token| {
+                        n_terms += 1;
+                        if !self.vocabulary.contains_key(token) {
+                            n_oov += 1;
+                        }
+                    },
+                );
+                if n_terms == 0 {
+                    0.0
+                } else {
+                    n_oov as f64 / n_terms as f64
+                }
+            })
+            .collect()
+    }
+
+    /// Fit and transform
+    ///
+    /// This is a single pass vectorization. Returns an error if `norm` is set,
+    /// see `CountVectorizer::transform`.
+    pub fn fit_transform<I, D>(&mut self, X: I) -> Result<CsMat<i32>, EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        self.fit_transform_as(X)
+    }
+
+    /// Fit and transform, with values of type `N`
+    ///
+    /// See `CountVectorizer::transform_as`.
+    pub fn fit_transform_as<N, I, D>(&mut self, X: I) -> Result<CsMat<N>, EstimatorErr>
+    where
+        N: Scalar,
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        _check_norm::<N>(self.params.norm)?;
+        if self.params.vocabulary.is_some() {
+            return self.transform_as(X);
+        }
+
+        let mut tf = self._fit_transform(X);
+        _normalize_rows(&tf.indptr, &mut tf.data, self.params.norm);
+
+        Ok(CsMat::new(
+            (tf.indptr.len() - 1, self.n_features()),
+            tf.indptr,
+            tf.indices,
+            tf.data,
+        ))
+    }
+
+    /// Fit and compute the unnormalized document-term matrix
+    fn _fit_transform<N, I, D>(&mut self, X: I) -> CSRArray<N>
+    where
+        N: Scalar,
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        let mut tf = CSRArray {
+            indices: Vec::new(),
             indptr: Vec::new(),
             data: Vec::new(),
         };
@@ -287,188 +1260,832 @@ impl<T: Tokenizer + Sync> CountVectorizer<T> {
 
         let mut nnz: usize = 0;
         let mut indices_local: Vec<i32> = Vec::new();
+        let mut ngram_buffer = String::new();
 
-        let pipe = X.iter().map(|doc| doc.to_ascii_lowercase());
-
-        let mut vocabulary_size: i32 = 0;
-
-        for document in pipe {
-            let tokens = self.params.tokenizer.tokenize(&document);
+        // features are indexed in order of first occurrence, then sorted by name
+        let mut vocabulary = std::mem::take(&mut self.vocabulary);
+        vocabulary.clear();
+        // with pruning, binary counts are computed once document frequencies are known
+        let binary = self.params.binary && !self.params.prunes_features();
 
-            indices_local.clear();
-
-            for token in tokens {
-                match self.vocabulary.get(token) {
-                    Some(_id) => indices_local.push(*_id),
-                    None => {
-                        self.vocabulary.insert(token.to_string(), vocabulary_size);
-                        indices_local.push(vocabulary_size);
-                        vocabulary_size += 1;
+        match _parallel_pool(&self.params.thread_pool, self.params.n_jobs) {
+            #[cfg(feature = "rayon")]
+            Some(pool) => {
+                let n_jobs = pool.current_num_threads();
+                for chunk in _chunks(X.into_iter(), n_jobs * CHUNK_SIZE_PER_JOB) {
+                    // each job indexes a contiguous part of the chunk with its own vocabulary
+                    let part_size = chunk.len() / n_jobs + 1;
+                    let parts: Vec<Vec<D>> = _chunks(chunk.into_iter(), part_size).collect();
+                    let parts = pool.install(|| {
+                        parts
+                            .into_par_iter()
+                            .map(|documents| {
+                                let mut part_vocabulary = HashMap::new();
+                                let mut ngram_buffer = String::new();
+                                let rows: Vec<Vec<i32>> = documents
+                                    .iter()
+                                    .map(|doc| {
+                                        let mut indices_local = Vec::new();
+                                        self._index_document(
+                                            &mut part_vocabulary,
+                                            doc.as_ref(),
+                                            &mut indices_local,
+                                            &mut ngram_buffer,
+                                            true,
+                                        );
+                                        indices_local
+                                    })
+                                    .collect();
+                                (part_vocabulary, rows)
+                            })
+                            .collect::<Vec<_>>()
+                    });
+
+                    // merging parts in order gives the same indices as sequential processing
+                    for (part_vocabulary, rows) in parts {
+                        let mut terms: Vec<String> = vec![String::new(); part_vocabulary.len()];
+                        for (term, idx) in part_vocabulary {
+                            terms[idx as usize] = term;
+                        }
+                        let idx_map: Vec<i32> = terms
+                            .into_iter()
+                            .map(|term| {
+                                let vocabulary_size = vocabulary.len() as i32;
+                                *vocabulary.entry(term).or_insert(vocabulary_size)
+                            })
+                            .collect();
+                        for mut indices_local in rows {
+                            for idx in indices_local.iter_mut() {
+                                *idx = idx_map[*idx as usize];
+                            }
+                            indices_local.sort_unstable();
+                            _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz, binary);
+                        }
                     }
-                };
+                }
+            }
+            _ => {
+                for doc in X {
+                    self._index_document(
+                        &mut vocabulary,
+                        doc.as_ref(),
+                        &mut indices_local,
+                        &mut ngram_buffer,
+                        true,
+                    );
+                    // this takes 10-15% of the compute time
+                    indices_local.sort_unstable();
+                    _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz, binary);
+                }
             }
-            // this takes 10-15% of the compute time
-            indices_local.sort_unstable();
-            _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz);
         }
+        self.vocabulary = vocabulary;
 
         _sort_features(&mut tf, &mut self.vocabulary);
 
-        CsMat::new(
-            (tf.indptr.len() - 1, self.vocabulary.len()),
-            tf.indptr,
-            tf.indices,
-            tf.data,
-        )
+        self.stop_words.clear();
+        self.document_frequency.clear();
+        self.n_documents = 0;
+        if self.params.prunes_features() {
+            let n_samples = tf.indptr.len() - 1;
+            self.stop_words = _limit_features(
+                &mut tf,
+                &mut self.vocabulary,
+                self.params.min_df.n_documents(n_samples),
+                self.params.max_df.n_documents(n_samples),
+                self.params.max_features,
+                self.params.oov,
+            );
+            if self.params.binary {
+                for value in tf.data.iter_mut() {
+                    *value = N::from_count(1);
+                }
+            }
+        }
+        tf
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> CountVectorizer<T> {
+    /// Save the estimator, including its parameters and vocabulary, to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P, format: Format) -> Result<(), EstimatorErr> {
+        let file = File::create(path)?;
+        persistence::save(self, BufWriter::new(file), format)
     }
+
+    /// Load an estimator saved with `CountVectorizer::save`
+    pub fn load<P: AsRef<Path>>(path: P, format: Format) -> Result<Self, EstimatorErr> {
+        let file = File::open(path)?;
+        persistence::load(BufReader::new(file), format)
+    }
+}
+
+/// Hash function of the `HashingVectorizer`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HashFunction {
+    /// SeaHash, fast on long inputs
+    SeaHash,
+    /// 32 bit MurmurHash3, producing the same features as scikit-learn's `HashingVectorizer`
+    /// with the same `n_features`, `alternate_sign` and the default seed
+    Murmur3,
 }
 
-#[derive(Debug, Clone)]
+impl HashFunction {
+    /// Column index and sign of a feature
+    #[inline]
+    fn hash(self, token: &str, seed: u32, n_features: u64) -> (i32, bool) {
+        match self {
+            HashFunction::SeaHash => {
+                // set the RNG seeds to get reproducible hashing
+                let hash = seahash::hash_seeded(token.as_bytes(), 1, 1000, 200, 89 + seed as u64);
+                ((hash % n_features) as i32, hash >> 63 == 1)
+            }
+            HashFunction::Murmur3 => {
+                // same index and sign as scikit-learn, from the signed 32 bit hash
+                let hash = murmurhash3::murmurhash3_32(token.as_bytes(), seed) as i32;
+                let index = if hash == i32::MIN {
+                    (i32::MAX as u64 - (n_features - 1)) % n_features
+                } else {
+                    u64::from(hash.unsigned_abs()) % n_features
+                };
+                (index as i32, hash < 0)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HashingVectorizerParams<T> {
     n_features: u64,
-    lowercase: bool,
+    alternate_sign: bool,
+    hash_function: HashFunction,
+    seed: u32,
+    binary: bool,
+    norm: Norm,
+    case_folding: CaseFolding,
     tokenizer: T,
+    ngram_range: (usize, usize),
+    ngram_separator: String,
+    stop_words: Option<StopWordFilter>,
     n_jobs: usize,
+    #[serde(skip)]
+    thread_pool: Option<Arc<ThreadPool>>,
 }
 
-impl<T: Tokenizer + Clone> HashingVectorizerParams<T> {
+impl<T: Analyzer + Clone> HashingVectorizerParams<T> {
+    /// Convert all characters to lowercase before tokenizing
+    ///
+    /// This is a shortcut for `case_folding(CaseFolding::Lowercase)` when true and
+    /// `case_folding(CaseFolding::None)` when false.
     pub fn lowercase(&mut self, value: bool) -> HashingVectorizerParams<T> {
-        self.lowercase = value;
+        self.case_folding = if value {
+            CaseFolding::Lowercase
+        } else {
+            CaseFolding::None
+        };
+        self.clone()
+    }
+    /// Case folding applied to documents before tokenizing
+    pub fn case_folding(&mut self, value: CaseFolding) -> HashingVectorizerParams<T> {
+        self.case_folding = value;
         self.clone()
     }
     pub fn tokenizer(&mut self, value: T) -> HashingVectorizerParams<T> {
         self.tokenizer = value;
         self.clone()
     }
+    /// The lower and upper boundary of the range of n-values for token n-grams to be extracted
+    pub fn ngram_range(&mut self, min_n: usize, max_n: usize) -> HashingVectorizerParams<T> {
+        self.ngram_range = (min_n, max_n);
+        self.clone()
+    }
+    /// String used to join tokens in n-grams
+    pub fn ngram_separator(&mut self, value: &str) -> HashingVectorizerParams<T> {
+        self.ngram_separator = value.to_string();
+        self.clone()
+    }
+    /// Remove stop words from the tokens, before n-grams are computed
+    pub fn stop_words(&mut self, value: StopWordFilter) -> HashingVectorizerParams<T> {
+        self.stop_words = Some(value);
+        self.clone()
+    }
+    /// Alternate the sign of features with a bit of their hash
+    ///
+    /// This approximately conserves the inner product in the hashed space, even for small
+    /// `n_features`, and the output matrix has signed values.
+    pub fn alternate_sign(&mut self, value: bool) -> HashingVectorizerParams<T> {
+        self.alternate_sign = value;
+        self.clone()
+    }
+    /// Hash function used to map features to column indices
+    pub fn hash_function(&mut self, value: HashFunction) -> HashingVectorizerParams<T> {
+        self.hash_function = value;
+        self.clone()
+    }
+    /// Seed of the hash function
+    pub fn seed(&mut self, value: u32) -> HashingVectorizerParams<T> {
+        self.seed = value;
+        self.clone()
+    }
+    /// Number of features (columns) in the output matrix
+    ///
+    /// Must be between 1 and 2^31, so that column indices fit in an `i32`.
+    pub fn n_features(&mut self, value: u64) -> HashingVectorizerParams<T> {
+        self.n_features = value;
+        self.clone()
+    }
+    /// Set all non zero values to 1, or -1 with `alternate_sign`
+    pub fn binary(&mut self, value: bool) -> HashingVectorizerParams<T> {
+        self.binary = value;
+        self.clone()
+    }
+    /// Normalization of each row of the output matrix
+    ///
+    /// Only supported with a floating point output, see `HashingVectorizer::transform_as`.
+    pub fn norm(&mut self, value: Norm) -> HashingVectorizerParams<T> {
+        self.norm = value;
+        self.clone()
+    }
+    /// Number of threads used to process documents in parallel
+    ///
+    /// With `n_jobs > 1`, a dedicated thread pool is created when building the estimator.
+    /// This replaces any thread pool set with `thread_pool`.
     pub fn n_jobs(&mut self, value: usize) -> HashingVectorizerParams<T> {
         self.n_jobs = value;
+        self.thread_pool = None;
+        self.clone()
+    }
+    /// Process documents in parallel in an existing thread pool
+    ///
+    /// `n_jobs` is set to the number of threads of the pool.
+    #[cfg(feature = "rayon")]
+    pub fn thread_pool(&mut self, value: Arc<ThreadPool>) -> HashingVectorizerParams<T> {
+        self.n_jobs = value.current_num_threads();
+        self.thread_pool = Some(value);
         self.clone()
     }
     pub fn build(&mut self) -> Result<HashingVectorizer<T>, EstimatorErr> {
-        if self.n_jobs < 1 {
-            panic!("n_jobs={} must be > 0", self.n_jobs);
-        }
         Ok(HashingVectorizer {
-            params: self.clone(),
+            params: self.clone().validate()?,
         })
     }
 }
 
-impl<T: Tokenizer + Clone + Default> Default for HashingVectorizerParams<T> {
+impl<T> HashingVectorizerParams<T> {
+    /// Check the parameters, and create the thread pool if necessary
+    fn validate(mut self) -> Result<HashingVectorizerParams<T>, EstimatorErr> {
+        _check_n_jobs(self.n_jobs)?;
+        if self.n_features < 1 || self.n_features > (1 << 31) {
+            return Err(EstimatorErr::InvalidParams(format!(
+                "n_features={} must be between 1 and 2^31",
+                self.n_features
+            )));
+        }
+        _check_ngram_range(self.ngram_range)?;
+        self.thread_pool = _build_thread_pool(self.n_jobs, &self.thread_pool)?;
+        Ok(self)
+    }
+}
+
+impl<T: Analyzer + Clone + Default> Default for HashingVectorizerParams<T> {
     /// Create a new instance
     fn default() -> HashingVectorizerParams<T> {
         let tokenizer = T::default();
         HashingVectorizerParams {
             n_features: 1_048_576,
-            lowercase: true,
+            alternate_sign: false,
+            hash_function: HashFunction::SeaHash,
+            seed: 0,
+            binary: false,
+            norm: Norm::None,
+            case_folding: CaseFolding::Lowercase,
             tokenizer,
+            ngram_range: (1, 1),
+            ngram_separator: " ".to_string(),
+            stop_words: None,
             n_jobs: 1,
+            thread_pool: None,
         }
     }
 }
 
-impl<T: Tokenizer + Clone + Default> Default for HashingVectorizer<T> {
+impl<T: Analyzer + Clone + Default> Default for HashingVectorizer<T> {
     /// Create a new instance
     fn default() -> HashingVectorizer<T> {
         HashingVectorizerParams::default().build().unwrap()
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(try_from = "HashingVectorizerState<T>")]
 pub struct HashingVectorizer<T> {
     params: HashingVectorizerParams<T>,
 }
 
-impl<T: Tokenizer + Sync> HashingVectorizer<T> {
+/// Serialized `HashingVectorizer`, validated when loading
+#[derive(Deserialize)]
+struct HashingVectorizerState<T> {
+    params: HashingVectorizerParams<T>,
+}
+
+impl<T> std::convert::TryFrom<HashingVectorizerState<T>> for HashingVectorizer<T> {
+    type Error = EstimatorErr;
+
+    fn try_from(state: HashingVectorizerState<T>) -> Result<HashingVectorizer<T>, EstimatorErr> {
+        Ok(HashingVectorizer {
+            params: state.params.validate()?,
+        })
+    }
+}
+
+impl<T: Analyzer + Sync> HashingVectorizer<T> {
     /// Fit method
     ///
     /// The vectorizer is stateless, this has no effect
-    pub fn fit(self, _X: &[String]) -> Self {
+    pub fn fit<I, D>(self, _X: I) -> Self
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
         self
     }
 
     /// Transform method
-    pub fn transform(&self, X: &[String]) -> CsMat<i32> {
-        let mut tf = crate::math::CSRArray {
-            indices: Vec::new(),
-            indptr: Vec::new(),
-            data: Vec::new(),
-        };
-
-        tf.indptr.push(0);
+    ///
+    /// Documents can be provided by any iterator, and are processed as a stream.
+    ///
+    /// Returns an error if `norm` is set, as integer counts cannot be normalized. Use
+    /// `transform_as` with a floating point output type instead.
+    pub fn transform<I, D>(&self, X: I) -> Result<CsMat<i32>, EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        self.transform_as(X)
+    }
 
-        let mut nnz: usize = 0;
+    /// Transform, with values of type `N`
+    ///
+    /// Values are computed directly in the output type, without intermediary
+    /// copies. Returns an error if `norm` is set and `N` is not a floating point type.
+    pub fn transform_as<N, I, D>(&self, X: I) -> Result<CsMat<N>, EstimatorErr>
+    where
+        N: Scalar,
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        _check_norm::<N>(self.params.norm)?;
 
         let tokenize_hash = |doc: &str| -> Vec<i32> {
             // Closure to tokenize a document and returns hash indices for each token
 
+            let doc = self.params.case_folding.apply(doc);
             let mut indices_local: Vec<i32> = Vec::with_capacity(10);
-
-            for token in self.params.tokenizer.tokenize(doc) {
-                // set the RNG seeds to get reproducible hashing
-                let hash = seahash::hash_seeded(token.as_bytes(), 1, 1000, 200, 89);
-                let hash = (hash % self.params.n_features) as i32;
-
-                indices_local.push(hash);
-            }
+            let mut ngram_buffer = String::new();
+
+            _for_each_ngram(
+                _tokenize(&self.params.tokenizer, &self.params.stop_words, &doc),
+                self.params.ngram_range,
+                &self.params.ngram_separator,
+                &mut ngram_buffer,
+                |token| {
+                    let (index, negative) = self.params.hash_function.hash(
+                        token,
+                        self.params.seed,
+                        self.params.n_features,
+                    );
+                    if self.params.alternate_sign && negative {
+                        indices_local.push(!index);
+                    } else {
+                        indices_local.push(index);
+                    }
+                },
+            );
             // this takes 10-15% of the compute time
-            indices_local.sort_unstable();
+            if self.params.alternate_sign {
+                indices_local
+                    .sort_unstable_by_key(|value| if *value < 0 { !*value } else { *value });
+            } else {
+                indices_local.sort_unstable();
+            }
             indices_local
         };
 
-        let pipe: Box<dyn Iterator<Item = Vec<i32>>>;
-
-        if self.params.n_jobs == 1 {
-            // Sequential (streaming) pipelines
-            pipe = Box::new(
-                X.iter()
-                    // String.to_lowercase() is very slow
-                    // https://www.reddit.com/r/rust/comments/6wbru2/performance_issue_can_i_avoid_of_using_the_slow/
-                    // https://github.com/rust-lang/rust/issues/26244
-                    // Possibly use: https://github.com/JuliaStrings/utf8proc
-                    // http://www.unicode.org/faq/casemap_charprop.html
-                    .map(|doc| doc.to_ascii_lowercase())
-                    .map(|doc| tokenize_hash(&doc)),
-            );
-        } else if self.params.n_jobs > 1 {
-            #[cfg(not(feature = "rayon"))]
-            {
-                panic!("vtext not built with rayon support; got n_jobs > 1");
+        let mut tf = _vectorize(
+            X,
+            _parallel_pool(&self.params.thread_pool, self.params.n_jobs),
+            self.params.binary,
+            tokenize_hash,
+        );
+        _normalize_rows(&tf.indptr, &mut tf.data, self.params.norm);
+
+        Ok(CsMat::new(
+            (tf.indptr.len() - 1, self.params.n_features as usize),
+            tf.indptr,
+            tf.indices,
+            tf.data,
+        ))
+    }
+
+    /// Fit and transform
+    ///
+    pub fn fit_transform<I, D>(&self, X: I) -> Result<CsMat<i32>, EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        self.transform(X)
+    }
+
+    /// Fit and transform, with values of type `N`
+    pub fn fit_transform_as<N, I, D>(&self, X: I) -> Result<CsMat<N>, EstimatorErr>
+    where
+        N: Scalar,
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        self.transform_as(X)
+    }
+}
+
+/// Row normalization applied to vectorized features
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Norm {
+    /// No normalization
+    None,
+    /// Sum of absolute values of each row is 1
+    L1,
+    /// Sum of squares of each row is 1
+    L2,
+}
+
+/// Type of the values of a document-term matrix
+///
+/// Implemented for `i32`, `i64`, `f32` and `f64`. Only floating point values
+/// can be normalized.
+pub trait Scalar: Copy + Default + Send + Sync + 'static {
+    /// Whether this is a floating point type
+    const IS_FLOAT: bool;
+
+    fn from_count(count: i32) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar {
+    ($type:ty, $is_float:expr) => {
+        impl Scalar for $type {
+            const IS_FLOAT: bool = $is_float;
+
+            #[inline]
+            fn from_count(count: i32) -> Self {
+                count as $type
+            }
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                value as $type
             }
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    };
+}
 
-            #[cfg(feature = "rayon")]
-            {
-                // Parallel pipeline. The scaling is reasonably good, however it uses more
-                // memory as all the tokens need to be collected into a Vec
-
-                // TODO: explicitly use self.thread_pool, currently the global thread pool is used
-                pipe = Box::new(
-                    X.par_iter()
-                        .map(|doc| doc.to_ascii_lowercase())
-                        .map(|doc| tokenize_hash(&doc))
-                        .collect::<Vec<Vec<i32>>>()
-                        .into_iter(),
-                );
+impl_scalar!(i32, false);
+impl_scalar!(i64, false);
+impl_scalar!(f32, true);
+impl_scalar!(f64, true);
+
+/// Check that the output values can be normalized
+fn _check_norm<N: Scalar>(norm: Norm) -> Result<(), EstimatorErr> {
+    if norm != Norm::None && !N::IS_FLOAT {
+        return Err(EstimatorErr::InvalidParams(format!(
+            "norm={:?} requires a floating point output type",
+            norm
+        )));
+    }
+    Ok(())
+}
+
+/// Normalize each row of a CSR array in place
+fn _normalize_rows<N: Scalar>(indptr: &[usize], data: &mut [N], norm: Norm) {
+    if norm == Norm::None {
+        return;
+    }
+    for row in indptr.windows(2) {
+        let row_data = &mut data[row[0]..row[1]];
+        let row_norm = match norm {
+            Norm::L1 => row_data.iter().map(|x| x.to_f64().abs()).sum::<f64>(),
+            Norm::L2 => row_data
+                .iter()
+                .map(|x| x.to_f64() * x.to_f64())
+                .sum::<f64>()
+                .sqrt(),
+            Norm::None => 1.0,
+        };
+        if row_norm > 0.0 {
+            for x in row_data.iter_mut() {
+                *x = N::from_f64(x.to_f64() / row_norm);
             }
-        } else {
-            panic!("n_jobs={} must be > 0", self.params.n_jobs);
         }
+    }
+}
+
+/// Builder for the TF-IDF transformer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TfidfTransformerParams {
+    norm: Norm,
+    use_idf: bool,
+    smooth_idf: bool,
+    sublinear_tf: bool,
+}
+
+impl TfidfTransformerParams {
+    pub fn norm(&mut self, value: Norm) -> TfidfTransformerParams {
+        self.norm = value;
+        self.clone()
+    }
+    pub fn use_idf(&mut self, value: bool) -> TfidfTransformerParams {
+        self.use_idf = value;
+        self.clone()
+    }
+    pub fn smooth_idf(&mut self, value: bool) -> TfidfTransformerParams {
+        self.smooth_idf = value;
+        self.clone()
+    }
+    pub fn sublinear_tf(&mut self, value: bool) -> TfidfTransformerParams {
+        self.sublinear_tf = value;
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<TfidfTransformer, EstimatorErr> {
+        Ok(TfidfTransformer {
+            params: self.clone(),
+            idf: None,
+        })
+    }
+}
 
-        for indices_local in pipe {
-            _sum_duplicates(&mut tf, indices_local.as_slice(), &mut nnz);
+impl Default for TfidfTransformerParams {
+    /// Create a new instance
+    fn default() -> TfidfTransformerParams {
+        TfidfTransformerParams {
+            norm: Norm::L2,
+            use_idf: true,
+            smooth_idf: true,
+            sublinear_tf: false,
         }
+    }
+}
 
-        CsMat::new(
-            (tf.indptr.len() - 1, self.params.n_features as usize),
-            tf.indptr,
-            tf.indices,
-            tf.data,
-        )
+impl Default for TfidfTransformer {
+    /// Create a new instance
+    fn default() -> TfidfTransformer {
+        TfidfTransformerParams::default().build().unwrap()
     }
+}
 
-    /// Fit and transform
+/// TF-IDF transformer
+///
+/// Transforms a count matrix to a normalized tf or tf-idf representation,
+/// following the same conventions as `TfidfTransformer` in scikit-learn.
+///
+/// The inverse document frequency of a term `t` is computed as
+/// `idf(t) = ln((1 + n) / (1 + df(t))) + 1` when `smooth_idf=true` and
+/// `idf(t) = ln(n / df(t)) + 1` otherwise, where `n` is the number of documents
+/// and `df(t)` the number of documents that contain the term.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TfidfTransformer {
+    pub params: TfidfTransformerParams,
+    /// Inverse document frequency weights, available once fitted
+    pub idf: Option<Vec<f64>>,
+}
+
+impl TfidfTransformer {
+    /// Fit the estimator
     ///
-    pub fn fit_transform(&self, X: &[String]) -> CsMat<i32> {
+    /// This computes the inverse document frequency of each feature
+    pub fn fit(&mut self, X: &CsMat<i32>) {
+        if !self.params.use_idf {
+            return;
+        }
+        let n_samples = X.rows() as f64;
+        let mut df: Vec<f64> = vec![0.0; X.cols()];
+        for (&value, (_row, col)) in X.iter() {
+            if value != 0 {
+                df[col] += 1.0;
+            }
+        }
+        let smooth = if self.params.smooth_idf { 1.0 } else { 0.0 };
+        let idf = df
+            .iter()
+            .map(|df| ((n_samples + smooth) / (df + smooth)).ln() + 1.0)
+            .collect();
+        self.idf = Some(idf);
+    }
+
+    /// Transform
+    ///
+    /// Converts a count matrix to a tf-idf weighted CSR matrix
+    pub fn transform(&self, X: &CsMat<i32>) -> Result<CsMat<f64>, EstimatorErr> {
+        let X = if X.is_csr() { X.clone() } else { X.to_csr() };
+        let idf = match (self.params.use_idf, &self.idf) {
+            (false, _) => None,
+            (true, Some(idf)) => {
+                if idf.len() != X.cols() {
+                    return Err(EstimatorErr::InvalidParams(format!(
+                        "input has {} features, while TfidfTransformer was fitted with {}",
+                        X.cols(),
+                        idf.len()
+                    )));
+                }
+                Some(idf)
+            }
+            (true, None) => {
+                return Err(EstimatorErr::NotFitted("TfidfTransformer".to_string()));
+            }
+        };
+
+        let shape = X.shape();
+        let (indptr, indices, data) = X.into_raw_storage();
+        let mut data: Vec<f64> = data
+            .iter()
+            .map(|&tf| {
+                if self.params.sublinear_tf && tf > 0 {
+                    (tf as f64).ln() + 1.0
+                } else {
+                    tf as f64
+                }
+            })
+            .collect();
+        if let Some(idf) = idf {
+            for (value, col) in data.iter_mut().zip(indices.iter()) {
+                *value *= idf[*col];
+            }
+        }
+        _normalize_rows(&indptr, &mut data, self.params.norm);
+
+        Ok(CsMat::new(shape, indptr, indices, data))
+    }
+
+    /// Fit and transform
+    pub fn fit_transform(&mut self, X: &CsMat<i32>) -> Result<CsMat<f64>, EstimatorErr> {
+        self.fit(X);
         self.transform(X)
     }
 }
+
+/// Builder for the TF-IDF vectorizer
+///
+/// Combines the parameters of `CountVectorizerParams` and `TfidfTransformerParams`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TfidfVectorizerParams<T> {
+    count_params: CountVectorizerParams<T>,
+    tfidf_params: TfidfTransformerParams,
+}
+
+impl<T: Analyzer + Clone> TfidfVectorizerParams<T> {
+    pub fn lowercase(&mut self, value: bool) -> TfidfVectorizerParams<T> {
+        self.count_params.lowercase(value);
+        self.clone()
+    }
+    pub fn case_folding(&mut self, value: CaseFolding) -> TfidfVectorizerParams<T> {
+        self.count_params.case_folding(value);
+        self.clone()
+    }
+    pub fn tokenizer(&mut self, value: T) -> TfidfVectorizerParams<T> {
+        self.count_params.tokenizer(value);
+        self.clone()
+    }
+    pub fn ngram_range(&mut self, min_n: usize, max_n: usize) -> TfidfVectorizerParams<T> {
+        self.count_params.ngram_range(min_n, max_n);
+        self.clone()
+    }
+    pub fn ngram_separator(&mut self, value: &str) -> TfidfVectorizerParams<T> {
+        self.count_params.ngram_separator(value);
+        self.clone()
+    }
+    pub fn stop_words(&mut self, value: StopWordFilter) -> TfidfVectorizerParams<T> {
+        self.count_params.stop_words(value);
+        self.clone()
+    }
+    pub fn min_df<D: Into<DocumentFrequency>>(&mut self, value: D) -> TfidfVectorizerParams<T> {
+        self.count_params.min_df(value);
+        self.clone()
+    }
+    pub fn max_df<D: Into<DocumentFrequency>>(&mut self, value: D) -> TfidfVectorizerParams<T> {
+        self.count_params.max_df(value);
+        self.clone()
+    }
+    pub fn max_features(&mut self, value: Option<usize>) -> TfidfVectorizerParams<T> {
+        self.count_params.max_features(value);
+        self.clone()
+    }
+    pub fn vocabulary(&mut self, value: HashMap<String, i32>) -> TfidfVectorizerParams<T> {
+        self.count_params.vocabulary(value);
+        self.clone()
+    }
+    pub fn oov(&mut self, value: OovStrategy) -> TfidfVectorizerParams<T> {
+        self.count_params.oov(value);
+        self.clone()
+    }
+    pub fn binary(&mut self, value: bool) -> TfidfVectorizerParams<T> {
+        self.count_params.binary(value);
+        self.clone()
+    }
+    pub fn n_jobs(&mut self, value: usize) -> TfidfVectorizerParams<T> {
+        self.count_params.n_jobs(value);
+        self.clone()
+    }
+    #[cfg(feature = "rayon")]
+    pub fn thread_pool(&mut self, value: Arc<ThreadPool>) -> TfidfVectorizerParams<T> {
+        self.count_params.thread_pool(value);
+        self.clone()
+    }
+    pub fn norm(&mut self, value: Norm) -> TfidfVectorizerParams<T> {
+        self.tfidf_params.norm(value);
+        self.clone()
+    }
+    pub fn use_idf(&mut self, value: bool) -> TfidfVectorizerParams<T> {
+        self.tfidf_params.use_idf(value);
+        self.clone()
+    }
+    pub fn smooth_idf(&mut self, value: bool) -> TfidfVectorizerParams<T> {
+        self.tfidf_params.smooth_idf(value);
+        self.clone()
+    }
+    pub fn sublinear_tf(&mut self, value: bool) -> TfidfVectorizerParams<T> {
+        self.tfidf_params.sublinear_tf(value);
+        self.clone()
+    }
+    pub fn build(&mut self) -> Result<TfidfVectorizer<T>, EstimatorErr> {
+        Ok(TfidfVectorizer {
+            count_vectorizer: self.count_params.build()?,
+            tfidf_transformer: self.tfidf_params.build()?,
+        })
+    }
+}
+
+impl<T: Analyzer + Clone + Default> Default for TfidfVectorizerParams<T> {
+    /// Create a new instance
+    fn default() -> TfidfVectorizerParams<T> {
+        TfidfVectorizerParams {
+            count_params: CountVectorizerParams::default(),
+            tfidf_params: TfidfTransformerParams::default(),
+        }
+    }
+}
+
+impl<T: Analyzer + Clone + Default> Default for TfidfVectorizer<T> {
+    /// Create a new instance
+    fn default() -> TfidfVectorizer<T> {
+        TfidfVectorizerParams::default().build().unwrap()
+    }
+}
+
+/// TF-IDF vectorizer
+///
+/// Equivalent to a `CountVectorizer` followed by a `TfidfTransformer`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TfidfVectorizer<T> {
+    pub count_vectorizer: CountVectorizer<T>,
+    pub tfidf_transformer: TfidfTransformer,
+}
+
+impl<T: Analyzer + Sync> TfidfVectorizer<T> {
+    /// Fit the estimator
+    ///
+    /// This lists the vocabulary and computes the inverse document frequencies
+    pub fn fit<I, D>(&mut self, X: I) -> Result<(), EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        let counts = self.count_vectorizer.fit_transform(X)?;
+        self.tfidf_transformer.fit(&counts);
+        Ok(())
+    }
+
+    /// Transform
+    ///
+    /// Converts a sequence of text documents to a tf-idf weighted CSR Matrix
+    pub fn transform<I, D>(&mut self, X: I) -> Result<CsMat<f64>, EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        let counts = self.count_vectorizer.transform(X)?;
+        self.tfidf_transformer.transform(&counts)
+    }
+
+    /// Fit and transform
+    pub fn fit_transform<I, D>(&mut self, X: I) -> Result<CsMat<f64>, EstimatorErr>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<str> + Send,
+    {
+        let counts = self.count_vectorizer.fit_transform(X)?;
+        self.tfidf_transformer.fit_transform(&counts)
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2019 vtext developers
+//
+// Licensed under the Apache License, Version 2.0,
+// <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
+// modified, or distributed except according to those terms.
+
+//! MurmurHash3 x86 32 bit hash function
+//!
+//! This is the hash function used in scikit-learn's `HashingVectorizer`,
+//! originally by Austin Appleby and released in the public domain.
+
+const C1: u32 = 0xcc9e_2d51;
+const C2: u32 = 0x1b87_3593;
+
+#[inline]
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h
+}
+
+/// Compute the 32 bit MurmurHash3 of a byte string
+pub(crate) fn murmurhash3_32(key: &[u8], seed: u32) -> u32 {
+    let mut h = seed;
+
+    let mut blocks = key.chunks_exact(4);
+    for block in &mut blocks {
+        let mut k = u32::from_le_bytes([block[0], block[1], block[2], block[3]]);
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h ^= k;
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let mut k: u32 = 0;
+        for (idx, byte) in tail.iter().enumerate() {
+            k ^= (*byte as u32) << (8 * idx);
+        }
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h ^= k;
+    }
+
+    h ^= key.len() as u32;
+    fmix32(h)
+}
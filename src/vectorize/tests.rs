@@ -4,9 +4,21 @@
 // <http://apache.org/licenses/LICENSE-2.0>. This file may not be copied,
 // modified, or distributed except according to those terms.
 
+use crate::persistence::{self, Format};
 use crate::tokenize::*;
 use crate::vectorize::*;
-use ndarray::array;
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array2};
+#[cfg(feature = "rayon")]
+use std::sync::Arc;
+
+fn assert_dense_close(X: &CsMat<f64>, X_ref: &Array2<f64>) {
+    let X = X.to_dense();
+    assert_eq!(X.shape(), X_ref.shape());
+    for (x, x_ref) in X.iter().zip(X_ref.iter()) {
+        assert_abs_diff_eq!(x, x_ref, epsilon = 1e-12);
+    }
+}
 
 #[test]
 fn test_count_vectorizer_simple() {
@@ -15,7 +27,7 @@ fn test_count_vectorizer_simple() {
     let documents = vec!["cat dog cat".to_string()];
     let mut vect = CountVectorizer::<RegexpTokenizer>::default();
 
-    let X = vect.fit_transform(&documents);
+    let X = vect.fit_transform(&documents).unwrap();
     assert_eq!(X.to_dense(), array![[2, 1]]);
 
     // Example 1
@@ -26,12 +38,12 @@ fn test_count_vectorizer_simple() {
     let X_ref = array![[0, 1, 0, 1, 1, 2], [1, 0, 1, 0, 3, 1]];
     let mut vect = CountVectorizer::<RegexpTokenizer>::default();
 
-    let X = vect.fit_transform(&documents);
+    let X = vect.fit_transform(&documents).unwrap();
     assert_eq!(X.to_dense().shape(), X_ref.shape());
     assert_eq!(X.to_dense(), X_ref);
 
     vect.fit(&documents);
-    let X = vect.transform(&documents);
+    let X = vect.transform(&documents).unwrap();
     assert_eq!(X.to_dense().shape(), X_ref.shape());
     assert_eq!(X.to_dense(), X_ref);
 }
@@ -41,10 +53,10 @@ fn test_vectorize_empty_countvectorizer() {
     let documents = vec!["some tokens".to_string(), "".to_string()];
 
     let mut vect = CountVectorizer::<RegexpTokenizer>::default();
-    vect.fit_transform(&documents);
+    vect.fit_transform(&documents).unwrap();
 
     vect.fit(&documents);
-    vect.transform(&documents);
+    vect.transform(&documents).unwrap();
 }
 
 #[test]
@@ -52,26 +64,173 @@ fn test_vectorize_empty_hashingvectorizer() {
     let documents = vec!["some tokens".to_string(), "".to_string()];
 
     let vect = HashingVectorizer::<RegexpTokenizer>::default();
-    vect.fit_transform(&documents);
+    vect.fit_transform(&documents).unwrap();
 
-    vect.transform(&documents);
+    vect.transform(&documents).unwrap();
 }
 
 #[test]
 fn test_count_vectorizer_fit_transform() {
     for documents in &[vec!["cat dog cat".to_string()]] {
         let mut vect = CountVectorizer::<RegexpTokenizer>::default();
-        vect.fit(&documents);
-        let X = vect.transform(&documents);
+        vect.fit(documents);
+        let X = vect.transform(documents).unwrap();
 
         let mut vect2 = CountVectorizer::<RegexpTokenizer>::default();
-        let X2 = vect2.fit_transform(&documents);
+        let X2 = vect2.fit_transform(documents).unwrap();
         assert_eq!(vect.vocabulary, vect2.vocabulary);
         println!("{:?}", vect.vocabulary);
         assert_eq!(X.to_dense(), X2.to_dense());
     }
 }
 
+#[test]
+fn test_count_vectorizer_streaming_input() {
+    let documents: Vec<String> = (0..5000)
+        .map(|idx| format!("document {} with {} tokens", idx % 7, idx % 11))
+        .collect();
+    let text = documents.join("\n");
+
+    let mut vect_ref = CountVectorizer::<RegexpTokenizer>::default();
+    let X_ref = vect_ref.fit_transform(&documents).unwrap();
+
+    // any iterator of string-like documents can be used, and is consumed as a stream
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    vect.fit(text.lines());
+    assert_eq!(vect.vocabulary, vect_ref.vocabulary);
+    assert_eq!(
+        vect.transform(text.lines().map(|doc| doc.to_string()))
+            .unwrap(),
+        X_ref
+    );
+    assert_eq!(vect.fit_transform(text.lines()).unwrap(), X_ref);
+}
+
+#[test]
+fn test_count_vectorizer_binary() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky sky sky is blue".to_string(),
+    ];
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .binary(true)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(X.data(), &[1, 1, 1, 1, 1, 1, 1, 1]);
+    let X = vect.transform(&documents).unwrap();
+    assert_eq!(X.data(), &[1, 1, 1, 1, 1, 1, 1, 1]);
+
+    // binary values are computed after pruning with max_features
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .binary(true)
+        .max_features(Some(1))
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.keys().collect::<Vec<_>>(), vec!["sky"]);
+    assert_eq!(X.data(), &[1, 1]);
+}
+
+#[test]
+fn test_count_vectorizer_norm() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky sky sky is blue".to_string(),
+    ];
+
+    // vocabulary: blue, in, is, moon, sky, the
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .norm(Norm::L1)
+        .build()
+        .unwrap();
+    let X: CsMat<f32> = vect.fit_transform_as(&documents).unwrap();
+    assert_eq!(X.indptr(), &[0, 4, 8]);
+    assert_eq!(
+        X.data(),
+        &[0.2, 0.2, 0.2, 0.4, 1. / 6., 1. / 6., 0.5, 1. / 6.]
+    );
+    let X: CsMat<f64> = vect.transform_as(&documents).unwrap();
+    assert_eq!(X.data()[6], 0.5);
+}
+
+#[test]
+fn test_count_vectorizer_norm_integer_output() {
+    // integer outputs cannot be normalized
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .norm(Norm::L2)
+        .build()
+        .unwrap();
+    let res = vect.fit_transform(["some text"]);
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+    let res = vect.transform(["some text"]);
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+    let res = vect.transform_as::<i64, _, _>(["some text"]);
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+}
+
+#[test]
+fn test_count_vectorizer_save_load() {
+    let documents = vec![
+        "The sea is blue".to_string(),
+        "The sky is blue, the sea is green".to_string(),
+        "Le ciel est bleu".to_string(),
+    ];
+    let tokenizer = TokenPipelineParams::default()
+        .tokenizer(RegexpTokenizer::default())
+        .stop_words(StopWordFilterParams::default().lang("en"))
+        .build()
+        .unwrap();
+    let mut vectorizer = CountVectorizerParams::default()
+        .tokenizer(tokenizer)
+        .ngram_range(1, 2)
+        .max_features(Some(6))
+        .build()
+        .unwrap();
+    let X = vectorizer.fit_transform(&documents).unwrap();
+
+    for format in [Format::Binary, Format::Json].iter() {
+        let mut buffer: Vec<u8> = Vec::new();
+        persistence::save(&vectorizer, &mut buffer, *format).unwrap();
+        let mut vectorizer2: CountVectorizer<TokenPipeline<RegexpTokenizer>> =
+            persistence::load(buffer.as_slice(), *format).unwrap();
+
+        assert_eq!(vectorizer2.vocabulary, vectorizer.vocabulary);
+        assert_eq!(vectorizer2.stop_words, vectorizer.stop_words);
+        let X2 = vectorizer2.transform(&documents).unwrap();
+        assert_eq!(X2, X);
+    }
+
+    let path = std::env::temp_dir().join("vtext_count_vectorizer_save_load.bin");
+    vectorizer.save(&path, Format::Binary).unwrap();
+    let mut vectorizer2 =
+        CountVectorizer::<TokenPipeline<RegexpTokenizer>>::load(&path, Format::Binary).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(vectorizer2.transform(&documents).unwrap(), X);
+}
+
+#[test]
+fn test_count_vectorizer_load_invalid() {
+    let mut vectorizer = CountVectorizer::<RegexpTokenizer>::default();
+    vectorizer.fit(&["The sea is blue".to_string()]);
+
+    // wrong format
+    let mut buffer: Vec<u8> = Vec::new();
+    persistence::save(&vectorizer, &mut buffer, Format::Json).unwrap();
+    let res: Result<CountVectorizer<RegexpTokenizer>, _> =
+        persistence::load(buffer.as_slice(), Format::Binary);
+    assert!(res.is_err());
+
+    // unsupported format version
+    let mut buffer: Vec<u8> = Vec::new();
+    persistence::save(&vectorizer, &mut buffer, Format::Binary).unwrap();
+    buffer[4] = 99;
+    let res: Result<CountVectorizer<RegexpTokenizer>, _> =
+        persistence::load(buffer.as_slice(), Format::Binary);
+    assert!(matches!(res, Err(EstimatorErr::InvalidFormat(_))));
+}
+
 #[test]
 fn test_hashing_vectorizer_simple() {
     // Results with scikit-learn 0.20.0
@@ -91,7 +250,7 @@ fn test_hashing_vectorizer_simple() {
 
     let vect = HashingVectorizer::<VTextTokenizer>::default();
     let vect = vect.fit(&documents);
-    let X = vect.transform(&documents);
+    let X = vect.transform(&documents).unwrap();
     assert_eq!(X.indptr(), &[0, 4, 8]);
     assert_eq!(X.data(), &[1, 2, 1, 1, 1, 1, 1, 1]);
     // this is not a thorough test because indices don't match exactly
@@ -108,12 +267,174 @@ fn test_hashing_vectorizer_simple() {
     indices.dedup();
     assert_eq!(indices_ref.len(), indices.len());
 
-    let X2 = vect.fit_transform(&documents);
+    let X2 = vect.fit_transform(&documents).unwrap();
     //assert_eq!(X.indices, X2.indices);
     assert_eq!(X.indptr(), X2.indptr());
     assert_eq!(X.data(), X2.data());
 }
 
+#[test]
+fn test_hashing_vectorizer_streaming_input() {
+    let documents: Vec<String> = (0..5000)
+        .map(|idx| format!("document {} with {} tokens", idx % 7, idx % 11))
+        .collect();
+    let text = documents.join("\n");
+
+    let vect = HashingVectorizer::<RegexpTokenizer>::default();
+    assert_eq!(
+        vect.transform(text.lines()).unwrap(),
+        vect.transform(documents.as_slice()).unwrap()
+    );
+}
+
+#[test]
+fn test_murmurhash3() {
+    use crate::vectorize::murmurhash3::murmurhash3_32;
+
+    assert_eq!(murmurhash3_32(b"", 0), 0);
+    assert_eq!(murmurhash3_32(b"", 1), 0x514e_28b7);
+    assert_eq!(murmurhash3_32(b"hello", 0), 0x248b_fa47);
+    assert_eq!(murmurhash3_32(b"Hello, world!", 1234), 0xfaf6_cdb3);
+    assert_eq!(
+        murmurhash3_32(b"The quick brown fox jumps over the lazy dog", 0),
+        0x2e4f_f723
+    );
+    // reference values from sklearn.utils.murmurhash3_32
+    assert_eq!(murmurhash3_32(b"foo", 0) as i32, -156_908_512);
+    assert_eq!(murmurhash3_32(b"foo", 42) as i32, -1_322_301_282);
+}
+
+#[test]
+fn test_hashing_vectorizer_alternate_sign() {
+    let documents = vec!["the cat sat on the mat".to_string(), "the dog".to_string()];
+
+    for hash_function in [HashFunction::SeaHash, HashFunction::Murmur3].iter() {
+        let params =
+            HashingVectorizerParams::<RegexpTokenizer>::default().hash_function(*hash_function);
+        let X = params
+            .clone()
+            .build()
+            .unwrap()
+            .transform(&documents)
+            .unwrap();
+        let X_signed = params
+            .clone()
+            .alternate_sign(true)
+            .build()
+            .unwrap()
+            .transform(&documents)
+            .unwrap();
+
+        // without collisions, only signs are changed
+        assert_eq!(X.indptr(), X_signed.indptr());
+        assert_eq!(X.indices(), X_signed.indices());
+        let data_abs: Vec<i32> = X_signed.data().iter().map(|x| x.abs()).collect();
+        assert_eq!(X.data(), data_abs.as_slice());
+        assert!(X_signed.data().iter().any(|x| *x < 0));
+        assert!(X_signed.data().iter().any(|x| *x > 0));
+    }
+}
+
+#[test]
+fn test_hashing_vectorizer_alternate_sign_collisions() {
+    // "ahb" and "bjr" collide with opposite signs, so they cancel out and zeros are not stored
+    let vectorizer = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .alternate_sign(true)
+        .hash_function(HashFunction::Murmur3)
+        .build()
+        .unwrap();
+    let X = vectorizer.transform(["ahb bjr", "bjr bjr ahb"]).unwrap();
+    assert_eq!(X.indptr(), &[0, 0, 1]);
+    assert_eq!(X.indices(), &[66751]);
+    assert_eq!(X.data(), &[-1]);
+}
+
+#[test]
+fn test_hashing_vectorizer_seed() {
+    let documents = vec!["the cat sat on the mat".to_string(), "the dog".to_string()];
+
+    for hash_function in [HashFunction::SeaHash, HashFunction::Murmur3].iter() {
+        let params =
+            HashingVectorizerParams::<RegexpTokenizer>::default().hash_function(*hash_function);
+        let X = params
+            .clone()
+            .build()
+            .unwrap()
+            .transform(&documents)
+            .unwrap();
+        let X_seed = params
+            .clone()
+            .seed(1)
+            .build()
+            .unwrap()
+            .transform(&documents)
+            .unwrap();
+        assert_ne!(X.indices(), X_seed.indices());
+    }
+}
+
+#[test]
+fn test_hashing_vectorizer_n_features() {
+    let documents = vec!["the cat sat on the mat".to_string(), "the dog".to_string()];
+
+    let vectorizer = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .n_features(16)
+        .build()
+        .unwrap();
+    let X = vectorizer.transform(&documents).unwrap();
+    assert_eq!(X.shape(), (2, 16));
+    assert!(X.indices().iter().all(|idx| *idx < 16));
+    // colliding tokens are summed, so all 8 tokens are counted
+    assert_eq!(X.data().iter().sum::<i32>(), 8);
+
+    for n_features in [1, 1 << 31].iter() {
+        let vectorizer = HashingVectorizerParams::<RegexpTokenizer>::default()
+            .n_features(*n_features)
+            .alternate_sign(true)
+            .build()
+            .unwrap();
+        let X = vectorizer.transform(&documents).unwrap();
+        assert_eq!(X.cols(), *n_features as usize);
+    }
+}
+
+#[test]
+fn test_hashing_vectorizer_invalid_n_features() {
+    for n_features in [0, (1 << 31) + 1].iter() {
+        let res = HashingVectorizerParams::<RegexpTokenizer>::default()
+            .n_features(*n_features)
+            .build();
+        assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+    }
+}
+
+#[test]
+fn test_hashing_vectorizer_binary_norm() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky sky sky is blue".to_string(),
+    ];
+
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .hash_function(HashFunction::Murmur3)
+        .alternate_sign(true)
+        .binary(true)
+        .norm(Norm::L2)
+        .build()
+        .unwrap();
+    let X: CsMat<f64> = vect.transform_as(&documents).unwrap();
+    for row in X.outer_iterator() {
+        let norm: f64 = row.data().iter().map(|x| x * x).sum();
+        assert_abs_diff_eq!(norm, 1.0, epsilon = 1e-12);
+        assert!(row.data().iter().all(|x| x.abs() == row.data()[0].abs()));
+    }
+    assert!(X.data().iter().any(|x| *x < 0.0));
+
+    // integer outputs cannot be normalized
+    let res = vect.transform(&documents);
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+}
+
 #[test]
 fn test_empty_dataset() {
     let documents: Vec<String> = vec![];
@@ -124,9 +445,9 @@ fn test_empty_dataset() {
         .build()
         .unwrap();
 
-    let X = vectorizer.fit_transform(&documents);
-    assert_eq!(X.data(), &[]);
-    assert_eq!(X.indices(), &[]);
+    let X = vectorizer.fit_transform(&documents).unwrap();
+    assert_eq!(X.data(), &[] as &[i32]);
+    assert_eq!(X.indices(), &[] as &[usize]);
     assert_eq!(X.indptr(), &[0]);
 
     let vectorizer = HashingVectorizerParams::default()
@@ -134,9 +455,9 @@ fn test_empty_dataset() {
         .build()
         .unwrap();
 
-    let X = vectorizer.fit_transform(&documents);
-    assert_eq!(X.data(), &[]);
-    assert_eq!(X.indices(), &[]);
+    let X = vectorizer.fit_transform(&documents).unwrap();
+    assert_eq!(X.data(), &[] as &[i32]);
+    assert_eq!(X.indices(), &[] as &[usize]);
     assert_eq!(X.indptr(), &[0]);
 }
 
@@ -204,5 +525,901 @@ fn test_vectorizers_n_jobs() {
         .build()
         .unwrap();
     assert_eq!(vect.params.n_jobs, 2);
-    let X = vect.fit(&documents);
+    // a dedicated thread pool is created
+    let pool = vect.params.thread_pool.clone().unwrap();
+    assert_eq!(pool.current_num_threads(), 2);
+    vect.fit(&documents);
+
+    // an existing thread pool can be provided
+    let pool = Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap(),
+    );
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .thread_pool(pool.clone())
+        .build()
+        .unwrap();
+    assert_eq!(vect.params.n_jobs, 3);
+    assert!(Arc::ptr_eq(
+        vect.params.thread_pool.as_ref().unwrap(),
+        &pool
+    ));
+    let X = vect.transform(&documents).unwrap();
+    let X_ref = HashingVectorizer::<RegexpTokenizer>::default()
+        .transform(&documents)
+        .unwrap();
+    assert_eq!(X, X_ref);
+
+    // setting n_jobs replaces the thread pool
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .thread_pool(pool.clone())
+        .n_jobs(2)
+        .build()
+        .unwrap();
+    assert!(!Arc::ptr_eq(
+        vect.params.thread_pool.as_ref().unwrap(),
+        &pool
+    ));
+}
+
+#[test]
+#[cfg(not(feature = "rayon"))]
+fn test_vectorizers_n_jobs_without_rayon() {
+    let res = CountVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(2)
+        .build();
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+    let res = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(2)
+        .build();
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_vectorizers_n_jobs_save_load() {
+    let documents = vec![
+        String::from("the moon in the sky"),
+        String::from("the sky is blue"),
+    ];
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(2)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+
+    // the thread pool is created when loading the estimator
+    let mut buffer: Vec<u8> = Vec::new();
+    persistence::save(&vect, &mut buffer, Format::Binary).unwrap();
+    let mut vect2: CountVectorizer<RegexpTokenizer> =
+        persistence::load(buffer.as_slice(), Format::Binary).unwrap();
+    let pool = vect2.params.thread_pool.clone().unwrap();
+    assert_eq!(pool.current_num_threads(), 2);
+    assert_eq!(vect2.transform(&documents).unwrap(), X);
+
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(2)
+        .build()
+        .unwrap();
+    let mut buffer: Vec<u8> = Vec::new();
+    persistence::save(&vect, &mut buffer, Format::Binary).unwrap();
+    let vect2: HashingVectorizer<RegexpTokenizer> =
+        persistence::load(buffer.as_slice(), Format::Binary).unwrap();
+    assert_eq!(vect2.params.thread_pool.unwrap().current_num_threads(), 2);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_vectorizers_n_jobs_streaming_input() {
+    let documents: Vec<String> = (0..5000)
+        .map(|idx| format!("document {} with {} tokens", idx % 7, idx % 11))
+        .collect();
+    let text = documents.join("\n");
+
+    // parallel processing by chunks gives the same result
+    let mut vect_ref = CountVectorizer::<RegexpTokenizer>::default();
+    let X_ref = vect_ref.fit_transform(&documents).unwrap();
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(3)
+        .build()
+        .unwrap();
+    vect.fit(text.lines());
+    assert_eq!(vect.vocabulary, vect_ref.vocabulary);
+    assert_eq!(vect.transform(text.lines()).unwrap(), X_ref);
+
+    let vect_ref = HashingVectorizer::<RegexpTokenizer>::default();
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(3)
+        .build()
+        .unwrap();
+    assert_eq!(
+        vect.transform(text.lines()).unwrap(),
+        vect_ref.transform(&documents).unwrap()
+    );
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_count_vectorizer_parallel_fit_transform() {
+    // new terms keep appearing across chunks, in an order unrelated to their names
+    let documents: Vec<String> = (0..7000)
+        .map(|idx| {
+            format!(
+                "term{} common w{} term{} x{}",
+                (idx * 7919) % 1013,
+                idx % 13,
+                idx % 97,
+                idx / 50
+            )
+        })
+        .collect();
+
+    let params = CountVectorizerParams::<RegexpTokenizer>::default().ngram_range(1, 2);
+    for params in [
+        params.clone(),
+        params.clone().binary(true),
+        params.clone().max_features(Some(100)).min_df(2),
+    ]
+    .iter()
+    {
+        let mut vect_ref = params.clone().build().unwrap();
+        let X_ref = vect_ref.fit_transform(&documents).unwrap();
+
+        for n_jobs in [2, 3, 8].iter() {
+            let mut vect = params.clone().n_jobs(*n_jobs).build().unwrap();
+            let X = vect.fit_transform(&documents).unwrap();
+            assert_eq!(vect.vocabulary, vect_ref.vocabulary);
+            assert_eq!(vect.stop_words, vect_ref.stop_words);
+            assert_eq!(X, X_ref);
+        }
+    }
+}
+
+#[test]
+fn test_vectorizers_invalid_n_jobs() {
+    let res = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(0)
+        .build();
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+    let res = CountVectorizerParams::<RegexpTokenizer>::default()
+        .n_jobs(0)
+        .build();
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+}
+
+#[test]
+fn test_vectorizers_load_invalid_n_jobs() {
+    // n_jobs is validated when loading an estimator
+    let vect = CountVectorizer::<RegexpTokenizer>::default();
+    let mut document = serde_json::to_value(&vect).unwrap();
+    document["params"]["n_jobs"] = serde_json::json!(0);
+    let res: Result<CountVectorizer<RegexpTokenizer>, _> = serde_json::from_value(document);
+    assert!(res.is_err());
+
+    let vect = HashingVectorizer::<RegexpTokenizer>::default();
+    let mut document = serde_json::to_value(&vect).unwrap();
+    document["params"]["n_jobs"] = serde_json::json!(0);
+    let res: Result<HashingVectorizer<RegexpTokenizer>, _> = serde_json::from_value(document);
+    assert!(res.is_err());
+
+    let params = CountVectorizerParams::<RegexpTokenizer>::default().n_jobs(0);
+    let res = CountVectorizer::with_params_and_vocabulary(params, HashMap::new());
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+}
+
+#[test]
+fn test_tfidf_transformer() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "The sky sky sky is blue".to_string(),
+    ];
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    let X = vect.fit_transform(&documents).unwrap();
+
+    // vocabulary: blue, in, is, moon, sky, the
+    let mut tfidf = TfidfTransformerParams::default()
+        .norm(Norm::None)
+        .build()
+        .unwrap();
+    let X_tfidf = tfidf.fit_transform(&X).unwrap();
+    let idf_1 = (3.0f64 / 2.0).ln() + 1.0;
+    assert_abs_diff_eq!(
+        tfidf.idf.as_ref().unwrap().as_slice(),
+        &[idf_1, idf_1, idf_1, idf_1, 1.0, 1.0][..]
+    );
+    let X_ref = array![
+        [0.0, idf_1, 0.0, idf_1, 1.0, 2.0],
+        [idf_1, 0.0, idf_1, 0.0, 3.0, 1.0]
+    ];
+    assert_dense_close(&X_tfidf, &X_ref);
+
+    // no smoothing, sublinear tf
+    let mut tfidf = TfidfTransformerParams::default()
+        .smooth_idf(false)
+        .sublinear_tf(true)
+        .norm(Norm::None)
+        .build()
+        .unwrap();
+    let X_tfidf = tfidf.fit_transform(&X).unwrap();
+    let idf_1 = (2.0f64).ln() + 1.0;
+    let X_ref = array![
+        [0.0, idf_1, 0.0, idf_1, 1.0, 2f64.ln() + 1.0],
+        [idf_1, 0.0, idf_1, 0.0, 3f64.ln() + 1.0, 1.0]
+    ];
+    assert_dense_close(&X_tfidf, &X_ref);
+
+    // rows are normalized with the default L2 norm
+    let mut tfidf = TfidfTransformer::default();
+    let X_tfidf = tfidf.fit_transform(&X).unwrap();
+    for row in X_tfidf.outer_iterator() {
+        let norm: f64 = row.data().iter().map(|x| x * x).sum();
+        assert_abs_diff_eq!(norm, 1.0, epsilon = 1e-12);
+    }
+
+    let mut tfidf = TfidfTransformerParams::default()
+        .norm(Norm::L1)
+        .build()
+        .unwrap();
+    let X_tfidf = tfidf.fit_transform(&X).unwrap();
+    for row in X_tfidf.outer_iterator() {
+        let norm: f64 = row.data().iter().sum();
+        assert_abs_diff_eq!(norm, 1.0, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_tfidf_transformer_not_fitted() {
+    let documents = vec!["cat dog cat".to_string()];
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    let X = vect.fit_transform(&documents).unwrap();
+
+    let tfidf = TfidfTransformer::default();
+    assert!(tfidf.transform(&X).is_err());
+
+    // without idf, fitting is not required
+    let tfidf = TfidfTransformerParams::default()
+        .use_idf(false)
+        .norm(Norm::None)
+        .build()
+        .unwrap();
+    let X_tf = tfidf.transform(&X).unwrap();
+    assert_eq!(X_tf.to_dense(), array![[2.0, 1.0]]);
+}
+
+#[test]
+fn test_tfidf_vectorizer() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "The sky sky sky is blue".to_string(),
+    ];
+    let mut vect = TfidfVectorizer::<RegexpTokenizer>::default();
+    let X = vect.fit_transform(&documents).unwrap();
+
+    let mut count_vect = CountVectorizer::<RegexpTokenizer>::default();
+    let mut tfidf = TfidfTransformer::default();
+    let X_ref = tfidf
+        .fit_transform(&count_vect.fit_transform(&documents).unwrap())
+        .unwrap();
+    assert_dense_close(&X, &X_ref.to_dense());
+
+    vect.fit(&documents).unwrap();
+    let X2 = vect.transform(&documents).unwrap();
+    assert_dense_close(&X, &X2.to_dense());
+}
+
+#[test]
+fn test_tfidf_vectorizer_streaming_input() {
+    let documents: Vec<String> = (0..5000)
+        .map(|idx| format!("document {} with {} tokens", idx % 7, idx % 11))
+        .collect();
+    let text = documents.join("\n");
+
+    let mut vect = TfidfVectorizer::<RegexpTokenizer>::default();
+    let X = vect.fit_transform(text.lines()).unwrap();
+    let X_ref = vect.fit_transform(&documents).unwrap();
+    assert_dense_close(&X, &X_ref.to_dense());
+}
+
+#[test]
+fn test_tfidf_vectorizer_fixed_vocabulary() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let vocabulary: HashMap<String, i32> = [("sky", 0), ("the", 1), ("moon", 2)]
+        .iter()
+        .map(|(term, idx)| (term.to_string(), *idx))
+        .collect();
+
+    let mut vect = TfidfVectorizerParams::<RegexpTokenizer>::default()
+        .vocabulary(vocabulary)
+        .build()
+        .unwrap();
+    assert_eq!(vect.fit_transform(&documents).unwrap().shape(), (2, 3));
+}
+
+#[test]
+fn test_count_vectorizer_ngram_range() {
+    let documents = vec!["cat dog cat".to_string(), "dog cat".to_string()];
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .ngram_range(1, 2)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    let mut vocabulary: Vec<(&str, i32)> = vect
+        .vocabulary
+        .iter()
+        .map(|(k, v)| (k.as_str(), *v))
+        .collect();
+    vocabulary.sort_by_key(|(_, v)| *v);
+    assert_eq!(
+        vocabulary,
+        vec![("cat", 0), ("cat dog", 1), ("dog", 2), ("dog cat", 3)]
+    );
+    assert_eq!(X.to_dense(), array![[2, 1, 1, 1], [1, 0, 1, 1]]);
+
+    vect.fit(&documents);
+    let X2 = vect.transform(&documents).unwrap();
+    assert_eq!(X.to_dense(), X2.to_dense());
+
+    // bigrams only, with a custom separator
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .ngram_range(2, 2)
+        .ngram_separator("_")
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 2);
+    assert_eq!(vect.vocabulary["cat_dog"], 0);
+    assert_eq!(vect.vocabulary["dog_cat"], 1);
+    assert_eq!(X.to_dense(), array![[1, 1], [0, 1]]);
+
+    // documents shorter than min_n produce no features
+    let X = vect.transform(&["cat".to_string()]).unwrap();
+    assert_eq!(X.nnz(), 0);
+
+    for (min_n, max_n) in &[(0, 1), (2, 1)] {
+        assert!(CountVectorizerParams::<RegexpTokenizer>::default()
+            .ngram_range(*min_n, *max_n)
+            .build()
+            .is_err());
+        assert!(HashingVectorizerParams::<RegexpTokenizer>::default()
+            .ngram_range(*min_n, *max_n)
+            .build()
+            .is_err());
+    }
+}
+
+#[test]
+fn test_hashing_vectorizer_ngram_range() {
+    let documents = vec!["cat dog cat".to_string(), "dog cat".to_string()];
+
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .ngram_range(1, 2)
+        .build()
+        .unwrap();
+    let X = vect.transform(&documents).unwrap();
+    assert_eq!(X.indptr(), &[0, 4, 7]);
+    assert_eq!(X.data().iter().sum::<i32>(), 8);
+
+    // unigram features are unchanged by adding bigrams
+    let vect_unigram = HashingVectorizer::<RegexpTokenizer>::default();
+    let X_unigram = vect_unigram.transform(&documents).unwrap();
+    for (row, row_unigram) in X.outer_iterator().zip(X_unigram.outer_iterator()) {
+        for (idx, value) in row_unigram.iter() {
+            assert_eq!(row.get(idx), Some(value));
+        }
+    }
+}
+
+#[test]
+fn test_count_vectorizer_char_ngrams() {
+    let documents = vec!["ab ba".to_string(), "ab".to_string()];
+
+    let tokenizer = CharacterTokenizerParams::default()
+        .ngram_range(1, 2)
+        .build()
+        .unwrap();
+    let mut vectorizer = CountVectorizerParams::default()
+        .tokenizer(tokenizer)
+        .build()
+        .unwrap();
+    let X = vectorizer.fit_transform(&documents).unwrap();
+    let mut vocabulary: Vec<(&String, &i32)> = vectorizer.vocabulary.iter().collect();
+    vocabulary.sort_by_key(|(_, idx)| **idx);
+    let vocabulary: Vec<&str> = vocabulary.iter().map(|(term, _)| term.as_str()).collect();
+    assert_eq!(vocabulary, &[" ", " b", "a", "ab", "b", "b ", "ba"]);
+    assert_eq!(
+        X.to_dense(),
+        array![[1, 1, 2, 1, 2, 1, 1], [0, 0, 1, 1, 1, 0, 0]]
+    );
+}
+
+#[test]
+fn test_vectorizers_char_wb_analyzer() {
+    let documents = vec!["ab ba".to_string(), "ab".to_string()];
+
+    let analyzer = CharacterWbAnalyzerParams::default()
+        .ngram_range(2, 2)
+        .build()
+        .unwrap();
+    let mut vectorizer = CountVectorizerParams::default()
+        .tokenizer(analyzer.clone())
+        .build()
+        .unwrap();
+    let X = vectorizer.fit_transform(&documents).unwrap();
+    assert_eq!(vectorizer.vocabulary.len(), 6);
+    assert_eq!(X.to_dense().sum(), 9);
+
+    let vectorizer = HashingVectorizerParams::default()
+        .tokenizer(analyzer)
+        .build()
+        .unwrap();
+    let X = vectorizer.fit_transform(&documents).unwrap();
+    assert_eq!(X.to_dense().sum(), 9);
+}
+
+#[test]
+fn test_vectorizers_case_folding() {
+    let documents = vec!["ÉCOLE école Straße STRASSE".to_string()];
+
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    vect.fit_transform(&documents).unwrap();
+    let mut vocabulary: Vec<&String> = vect.vocabulary.keys().collect();
+    vocabulary.sort();
+    assert_eq!(vocabulary, vec!["strasse", "straße", "école"]);
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .lowercase(false)
+        .build()
+        .unwrap();
+    vect.fit(&documents);
+    assert_eq!(vect.vocabulary.len(), 4);
+    assert!(vect.vocabulary.contains_key("ÉCOLE"));
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .case_folding(CaseFolding::Ascii)
+        .build()
+        .unwrap();
+    vect.fit(&documents);
+    assert!(vect.vocabulary.contains_key("École"));
+    assert!(vect.vocabulary.contains_key("strasse"));
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .case_folding(CaseFolding::Casefold)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 2);
+    assert_eq!(X.to_dense(), array![[2, 2]]);
+    let X2 = vect.transform(&documents).unwrap();
+    assert_eq!(X.to_dense(), X2.to_dense());
+
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .lowercase(false)
+        .build()
+        .unwrap();
+    assert_eq!(vect.transform(&documents).unwrap().nnz(), 4);
+    let vect = HashingVectorizer::<RegexpTokenizer>::default();
+    assert_eq!(vect.transform(&documents).unwrap().nnz(), 3);
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .case_folding(CaseFolding::Casefold)
+        .build()
+        .unwrap();
+    assert_eq!(vect.transform(&documents).unwrap().nnz(), 2);
+}
+
+#[test]
+fn test_count_vectorizer_min_max_df() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "The sky sky sky is blue".to_string(),
+        "the sun".to_string(),
+    ];
+
+    // "the" is in all documents, "sky" in 2
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .min_df(2)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 2);
+    assert_eq!(vect.vocabulary["sky"], 0);
+    assert_eq!(vect.vocabulary["the"], 1);
+    assert_eq!(X.to_dense(), array![[1, 2], [3, 1], [0, 1]]);
+    let mut stop_words: Vec<&String> = vect.stop_words.iter().collect();
+    stop_words.sort();
+    assert_eq!(stop_words, vec!["blue", "in", "is", "moon", "sun"]);
+
+    vect.fit(&documents);
+    assert_eq!(vect.vocabulary.len(), 2);
+    assert_eq!(X.to_dense(), vect.transform(&documents).unwrap().to_dense());
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .max_df(0.9)
+        .build()
+        .unwrap();
+    vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 6);
+    assert!(!vect.vocabulary.contains_key("the"));
+    assert!(vect.stop_words.contains("the"));
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .min_df(DocumentFrequency::Ratio(0.5))
+        .max_df(DocumentFrequency::Count(2))
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 1);
+    assert_eq!(X.to_dense(), array![[1], [3], [0]]);
+
+    assert!(CountVectorizerParams::<RegexpTokenizer>::default()
+        .max_df(1.5)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_count_vectorizer_max_features() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "The sky sky sky is blue".to_string(),
+    ];
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .max_features(Some(2))
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 2);
+    assert_eq!(vect.vocabulary["sky"], 0);
+    assert_eq!(vect.vocabulary["the"], 1);
+    assert_eq!(X.to_dense(), array![[1, 2], [3, 1]]);
+    assert_eq!(vect.stop_words.len(), 4);
+
+    // ties are broken by feature order
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .max_features(Some(3))
+        .build()
+        .unwrap();
+    vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 3);
+    assert_eq!(vect.vocabulary["blue"], 0);
+}
+
+#[test]
+fn test_count_vectorizer_partial_fit() {
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+
+    vect.partial_fit(["the sky is blue", "the moon"]);
+    assert_eq!(vect.vocabulary.len(), 5);
+    assert_eq!(vect.vocabulary["the"], 0);
+    assert_eq!(vect.vocabulary["moon"], 4);
+    assert_eq!(vect.document_frequency, vec![2, 1, 1, 1, 1]);
+    assert_eq!(vect.n_documents, 2);
+
+    // new terms are appended, existing indices are unchanged
+    vect.partial_fit(["a red moon", "the red sky"]);
+    assert_eq!(vect.vocabulary["the"], 0);
+    assert_eq!(vect.vocabulary["moon"], 4);
+    assert_eq!(vect.vocabulary["red"], 5);
+    assert_eq!(vect.document_frequency, vec![3, 2, 1, 1, 2, 2]);
+    assert_eq!(vect.n_documents, 4);
+
+    let X = vect.transform(["the red moon"]).unwrap();
+    assert_eq!(X.shape(), (1, 6));
+    assert_eq!(X.indices(), &[0, 4, 5]);
+
+    // fit learns a new vocabulary
+    vect.fit(["some text", "some other text"]);
+    assert_eq!(vect.vocabulary.len(), 3);
+    assert!(vect.document_frequency.is_empty());
+    assert_eq!(vect.n_documents, 0);
+}
+
+#[test]
+fn test_count_vectorizer_partial_fit_frozen() {
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    vect.partial_fit(["the sky is blue", "the moon"]);
+
+    // only document frequencies are updated
+    vect.frozen = true;
+    vect.partial_fit(["the green sky"]);
+    assert_eq!(vect.vocabulary.len(), 5);
+    assert!(!vect.vocabulary.contains_key("green"));
+    assert_eq!(vect.document_frequency, vec![3, 2, 1, 1, 1]);
+    assert_eq!(vect.n_documents, 3);
+}
+
+#[test]
+fn test_count_vectorizer_prune() {
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .min_df(2)
+        .build()
+        .unwrap();
+    assert!(matches!(vect.prune(), Err(EstimatorErr::NotFitted(_))));
+
+    vect.partial_fit(["the sky is blue", "the moon", "a red moon", "the red sky"]);
+    vect.frozen = true;
+    vect.partial_fit(["the green sky"]);
+    assert_eq!(vect.document_frequency, vec![4, 3, 1, 1, 2, 2]);
+
+    // pruning uses tracked document frequencies, and preserves the order of features
+    vect.prune().unwrap();
+    let mut vocabulary: Vec<(&String, &i32)> = vect.vocabulary.iter().collect();
+    vocabulary.sort_by_key(|(_, idx)| **idx);
+    assert_eq!(
+        vocabulary,
+        vec![
+            (&"the".to_string(), &0),
+            (&"sky".to_string(), &1),
+            (&"moon".to_string(), &2),
+            (&"red".to_string(), &3)
+        ]
+    );
+    assert_eq!(vect.document_frequency, vec![4, 3, 2, 2]);
+    assert!(vect.stop_words.contains("blue"));
+}
+
+#[test]
+fn test_count_vectorizer_get_feature_names() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    vect.fit(&documents);
+
+    assert_eq!(
+        vect.get_feature_names(),
+        vec!["blue", "in", "is", "moon", "sky", "the"]
+    );
+}
+
+#[test]
+fn test_count_vectorizer_inverse_transform() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    let X = vect.fit_transform(&documents).unwrap();
+
+    let terms = vect.inverse_transform(&X).unwrap();
+    assert_eq!(
+        terms,
+        vec![
+            vec!["in", "moon", "sky", "the"],
+            vec!["blue", "is", "sky", "the"]
+        ]
+    );
+
+    // CSC and floating point matrices are supported, zeros are ignored
+    let mut X_csc: CsMat<f64> = vect.transform_as(["the blue moon"]).unwrap().to_csc();
+    X_csc.data_mut()[0] = 0.0;
+    assert_eq!(
+        vect.inverse_transform(&X_csc).unwrap(),
+        vec![vec!["moon", "the"]]
+    );
+}
+
+#[test]
+fn test_count_vectorizer_inverse_transform_invalid() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    vect.fit(&documents);
+
+    // the number of columns must match
+    let X = HashingVectorizer::<RegexpTokenizer>::default()
+        .transform(&documents)
+        .unwrap();
+    assert!(matches!(
+        vect.inverse_transform(&X),
+        Err(EstimatorErr::InvalidParams(_))
+    ));
+}
+
+#[test]
+fn test_count_vectorizer_fixed_vocabulary() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let vocabulary: HashMap<String, i32> = [("sky", 0), ("the", 1), ("moon", 2)]
+        .iter()
+        .map(|(term, idx)| (term.to_string(), *idx))
+        .collect();
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .vocabulary(vocabulary.clone())
+        .min_df(2)
+        .build()
+        .unwrap();
+    // the vectorizer is already fitted
+    let X = vect.transform(&documents).unwrap();
+    assert_eq!(X.shape(), (2, 3));
+    assert_eq!(X.indices(), &[0, 1, 2, 0, 1]);
+    assert_eq!(X.data(), &[1, 2, 1, 1, 1]);
+
+    // fitting doesn't modify the vocabulary
+    assert_eq!(vect.fit_transform(&documents).unwrap(), X);
+    vect.fit(&documents);
+    vect.partial_fit(&documents);
+    assert_eq!(vect.vocabulary, vocabulary);
+    assert_eq!(vect.document_frequency, vec![2, 2, 1]);
+    assert!(matches!(vect.prune(), Err(EstimatorErr::InvalidParams(_))));
+}
+
+#[test]
+fn test_count_vectorizer_invalid_vocabulary() {
+    // indices must be unique and contiguous
+    for invalid in [
+        vec![],
+        vec![("sky", 0), ("the", 0)],
+        vec![("sky", 0), ("the", 2)],
+        vec![("sky", -1), ("the", 0)],
+    ]
+    .iter()
+    {
+        let vocabulary: HashMap<String, i32> = invalid
+            .iter()
+            .map(|(term, idx)| (term.to_string(), *idx))
+            .collect();
+        let res = CountVectorizerParams::<RegexpTokenizer>::default()
+            .vocabulary(vocabulary)
+            .build();
+        assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+    }
+}
+
+#[test]
+fn test_count_vectorizer_oov_unknown() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let new_documents = vec!["the sun is hot".to_string(), "".to_string()];
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .oov(OovStrategy::Unknown)
+        .build()
+        .unwrap();
+    vect.fit(&documents);
+    assert_eq!(vect.n_features(), 7);
+    assert_eq!(vect.get_feature_names()[6], "<unk>");
+    let X = vect.transform(&new_documents).unwrap();
+    assert_eq!(X.shape(), (2, 7));
+    assert_eq!(X.indices(), &[2, 5, 6]);
+    assert_eq!(X.data(), &[1, 1, 2]);
+    assert_eq!(
+        vect.inverse_transform(&X).unwrap()[0],
+        vec!["is", "the", "<unk>"]
+    );
+}
+
+#[test]
+fn test_count_vectorizer_oov_pruned_terms() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+
+    // pruned terms are counted as out-of-vocabulary in fit_transform
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .oov(OovStrategy::Unknown)
+        .min_df(2)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(X.shape(), (2, 3));
+    assert_eq!(X.indices(), &[0, 1, 2, 0, 1, 2]);
+    assert_eq!(X.data(), &[1, 2, 2, 1, 1, 2]);
+    assert_eq!(vect.transform(&documents).unwrap(), X);
+}
+
+#[test]
+fn test_count_vectorizer_oov_hash() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let new_documents = vec!["the sun is hot".to_string(), "".to_string()];
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .oov(OovStrategy::Hash(4))
+        .build()
+        .unwrap();
+    vect.fit(&documents);
+    let X = vect.transform(&new_documents).unwrap();
+    assert_eq!(X.shape(), (2, 10));
+    assert_eq!(X.data().iter().sum::<i32>(), 4);
+    assert!(X.indices()[2..].iter().all(|idx| *idx >= 6));
+    assert_eq!(vect.get_feature_names()[9], "<unk-3>");
+
+    let res = CountVectorizerParams::<RegexpTokenizer>::default()
+        .oov(OovStrategy::Hash(0))
+        .build();
+    assert!(matches!(res, Err(EstimatorErr::InvalidParams(_))));
+}
+
+#[test]
+fn test_count_vectorizer_oov_rate() {
+    let documents = vec![
+        "the moon in the sky".to_string(),
+        "the sky is blue".to_string(),
+    ];
+    let new_documents = vec!["the sun is hot".to_string(), "".to_string()];
+
+    // the rate doesn't depend on the oov strategy
+    let mut vect = CountVectorizer::<RegexpTokenizer>::default();
+    vect.fit(&documents);
+    assert_eq!(vect.oov_rate(&new_documents), vec![0.5, 0.0]);
+}
+
+#[test]
+fn test_vectorizers_stop_words() {
+    let documents = vec!["The fox is in the forest".to_string()];
+    let filter = StopWordFilterParams::default().lang("en").build().unwrap();
+
+    let mut vect = CountVectorizerParams::<RegexpTokenizer>::default()
+        .stop_words(filter.clone())
+        .ngram_range(1, 2)
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    let mut vocabulary: Vec<&String> = vect.vocabulary.keys().collect();
+    vocabulary.sort();
+    assert_eq!(vocabulary, vec!["forest", "fox", "fox forest"]);
+    vect.fit(&documents);
+    assert_eq!(X.to_dense(), vect.transform(&documents).unwrap().to_dense());
+
+    let vect = HashingVectorizerParams::<RegexpTokenizer>::default()
+        .stop_words(filter.clone())
+        .build()
+        .unwrap();
+    assert_eq!(vect.transform(&documents).unwrap().nnz(), 2);
+
+    // a tokenizer wrapped in the filter gives the same result
+    let mut vect = CountVectorizerParams::default()
+        .tokenizer(filter.wrap(RegexpTokenizer::default()))
+        .build()
+        .unwrap();
+    vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 2);
+}
+
+#[test]
+fn test_vectorizers_token_pipeline() {
+    let documents = vec!["The Fishes are swimming".to_string(), "a fish".to_string()];
+    let pipeline = TokenPipelineParams::default()
+        .tokenizer(RegexpTokenizer::default())
+        .stop_words(StopWordFilterParams::default().lang("en"))
+        .stem(crate::stem::SnowballStemmerParams::default())
+        .build()
+        .unwrap();
+
+    let mut vect = CountVectorizerParams::default()
+        .tokenizer(pipeline.clone())
+        .build()
+        .unwrap();
+    let X = vect.fit_transform(&documents).unwrap();
+    assert_eq!(vect.vocabulary.len(), 2);
+    assert_eq!(vect.vocabulary["fish"], 0);
+    assert_eq!(vect.vocabulary["swim"], 1);
+    assert_eq!(X.to_dense(), array![[1, 1], [1, 0]]);
+    vect.fit(&documents);
+    assert_eq!(X.to_dense(), vect.transform(&documents).unwrap().to_dense());
+
+    let vect = HashingVectorizerParams::default()
+        .tokenizer(pipeline)
+        .build()
+        .unwrap();
+    let X = vect.transform(&documents).unwrap();
+    assert_eq!(X.nnz(), 3);
 }